@@ -139,6 +139,9 @@ GO := env -u GOPATH $(OASIS_GO)
 # Path to the MKVS interoperability test helpers binary in go/.
 GO_TEST_HELPER_MKVS_PATH := storage/mkvs/interop/mkvs-test-helpers
 
+# Path to the staking transaction test vectors in go/.
+GO_TEST_HELPER_STAKING_VECTORS_PATH := staking/gen_vectors/staking-test-vectors.json
+
 # Path to the example signer plugin binary in go/.
 GO_EXAMPLE_PLUGIN_PATH := oasis-test-runner/scenario/pluginsigner/example_signer_plugin
 
@@ -0,0 +1,104 @@
+package beacon
+
+import (
+	"context"
+	"sync"
+
+	"github.com/prometheus/client_golang/prometheus"
+
+	"github.com/oasisprotocol/oasis-core/go/beacon/api"
+	"github.com/oasisprotocol/oasis-core/go/common/logging"
+)
+
+// Unlike oasis_worker_epoch_number and oasis_worker_epoch_transition_count, which are only
+// reported by runtime workers and labeled per runtime, these are reported by every node that
+// runs a consensus backend (e.g., validators without any runtimes).
+var (
+	beaconEpoch = prometheus.NewGauge(
+		prometheus.GaugeOpts{
+			Name: "oasis_beacon_epoch",
+			Help: "Current epoch as seen by the consensus beacon.",
+		},
+	)
+	beaconEpochTransitions = prometheus.NewCounter(
+		prometheus.CounterOpts{
+			Name: "oasis_beacon_epoch_transitions",
+			Help: "Number of epoch transitions observed by the consensus beacon.",
+		},
+	)
+	beaconCollectors = []prometheus.Collector{
+		beaconEpoch,
+		beaconEpochTransitions,
+	}
+
+	metricsOnce sync.Once
+)
+
+// MetricsUpdater is a beacon metric updater.
+type MetricsUpdater struct {
+	logger *logging.Logger
+
+	backend api.Backend
+
+	closeOnce sync.Once
+	closeCh   chan struct{}
+	closedCh  chan struct{}
+}
+
+// Cleanup performs cleanup.
+func (m *MetricsUpdater) Cleanup() {
+	m.closeOnce.Do(func() {
+		close(m.closeCh)
+		<-m.closedCh
+	})
+}
+
+func (m *MetricsUpdater) worker(ctx context.Context) {
+	defer close(m.closedCh)
+
+	ch, sub, err := m.backend.WatchEpochs(ctx)
+	if err != nil {
+		m.logger.Error("failed to watch epochs",
+			"err", err,
+		)
+		return
+	}
+	defer sub.Close()
+
+	var initialized bool
+	for {
+		select {
+		case <-m.closeCh:
+			return
+		case epoch, ok := <-ch:
+			if !ok {
+				return
+			}
+
+			beaconEpoch.Set(float64(epoch))
+			// The first epoch is the current one and is not a transition.
+			if initialized {
+				beaconEpochTransitions.Inc()
+			}
+			initialized = true
+		}
+	}
+}
+
+// NewMetricsUpdater creates a new beacon metrics updater.
+func NewMetricsUpdater(ctx context.Context, backend api.Backend) *MetricsUpdater {
+	metricsOnce.Do(func() {
+		prometheus.MustRegister(beaconCollectors...)
+	})
+
+	m := &MetricsUpdater{
+		logger:   logging.GetLogger("go/beacon/metrics"),
+		backend:  backend,
+		closeCh:  make(chan struct{}),
+		closedCh: make(chan struct{}),
+	}
+
+	go m.worker(ctx)
+
+	return m
+}
@@ -125,6 +125,14 @@ func UnmarshalRPC(data []byte, dst interface{}) error {
 	return decModeRPC.Unmarshal(data, dst)
 }
 
+// Valid checks whether the given byte vector is a single well-formed CBOR item without any
+// trailing data that also satisfies the restrictions imposed during decoding (e.g., no
+// indefinite-length items and no tags).
+func Valid(data []byte) error {
+	var raw RawMessage
+	return decMode.Unmarshal(data, &raw)
+}
+
 // MustUnmarshal deserializes a CBOR byte vector into a given type.
 // Panics if unmarshal fails.
 func MustUnmarshal(data []byte, dst interface{}) {
@@ -31,6 +31,16 @@ func TestOutOfMem3(t *testing.T) {
 	require.Error(err, "Invalid CBOR input should fail")
 }
 
+func TestValid(t *testing.T) {
+	require := require.New(t)
+
+	require.NoError(Valid(Marshal(map[string]uint64{"foo": 42})), "valid CBOR should pass")
+	require.Error(Valid([]byte("\x9b\x00\x00000000")), "truncated CBOR should fail")
+	require.Error(Valid([]byte("\x9f\x01\xff")), "indefinite-length items should fail")
+	require.Error(Valid(nil), "empty input should fail")
+	require.Error(Valid(append(Marshal(42), 0x01)), "trailing data should fail")
+}
+
 func TestEncoderDecoder(t *testing.T) {
 	require := require.New(t)
 
@@ -81,3 +91,19 @@ func TestDecodeUnknownField(t *testing.T) {
 	err = UnmarshalRPC(raw, &dec)
 	require.NoError(err, "unknown fields from RPC should pass")
 }
+
+func BenchmarkRoundTripLargeBatch(b *testing.B) {
+	// A batch of 1000 transactions of 1 KiB each.
+	batch := make([][]byte, 1000)
+	for i := range batch {
+		batch[i] = bytes.Repeat([]byte{byte(i)}, 1024)
+	}
+
+	b.ResetTimer()
+	for n := 0; n < b.N; n++ {
+		var dec [][]byte
+		if err := Unmarshal(Marshal(batch), &dec); err != nil {
+			b.Fatalf("Unmarshal: %v", err)
+		}
+	}
+}
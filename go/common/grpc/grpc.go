@@ -440,6 +440,8 @@ type ServerConfig struct { // nolint: maligned
 	// ClientCommonName is the expected common name on client TLS certificates. If not specified,
 	// the default identity.CommonName will be used.
 	ClientCommonName string
+	// Limits is the optional rate limiting and admission control configuration.
+	Limits *LimitsConfig
 	// CustomOptions is an array of extra options for the grpc server.
 	CustomOptions []grpc.ServerOption
 }
@@ -617,16 +619,24 @@ func NewServer(config *ServerConfig) (*Server, error) {
 		config.ClientCommonName = identity.CommonName
 	}
 	var wrapper *grpcWrapper
-	unaryInterceptors := []grpc.UnaryServerInterceptor{
+	var unaryInterceptors []grpc.UnaryServerInterceptor
+	var streamInterceptors []grpc.StreamServerInterceptor
+	if config.Limits != nil {
+		// Reject requests before doing any other work.
+		limiter := newLimiter(config.Limits)
+		unaryInterceptors = append(unaryInterceptors, limiter.unaryInterceptor)
+		streamInterceptors = append(streamInterceptors, limiter.streamInterceptor)
+	}
+	unaryInterceptors = append(unaryInterceptors,
 		logAdapter.unaryLogger,
 		serverUnaryErrorMapper,
 		auth.UnaryServerInterceptor(config.AuthFunc),
-	}
-	streamInterceptors := []grpc.StreamServerInterceptor{
+	)
+	streamInterceptors = append(streamInterceptors,
 		logAdapter.streamLogger,
 		serverStreamErrorMapper,
 		auth.StreamServerInterceptor(config.AuthFunc),
-	}
+	)
 	if config.InstallWrapper {
 		wrapper = newWrapper()
 		unaryInterceptors = append(unaryInterceptors, wrapper.unaryInterceptor)
@@ -640,6 +650,9 @@ func NewServer(config *ServerConfig) (*Server, error) {
 		grpc.KeepaliveParams(serverKeepAliveParams),
 		grpc.ForceServerCodec(&CBORCodec{}),
 	}
+	if config.Limits != nil && config.Limits.MaxConcurrentStreams > 0 {
+		sOpts = append(sOpts, grpc.MaxConcurrentStreams(config.Limits.MaxConcurrentStreams))
+	}
 	if config.Identity != nil && config.Identity.TLSCertificate != nil {
 		tlsConfig := &tls.Config{
 			ClientAuth: clientAuthType,
@@ -0,0 +1,223 @@
+package grpc
+
+import (
+	"context"
+	"net"
+	"sync"
+	"time"
+
+	any "github.com/golang/protobuf/ptypes/any"
+	spb "google.golang.org/genproto/googleapis/rpc/status"
+	"google.golang.org/grpc"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/peer"
+	"google.golang.org/grpc/status"
+
+	"github.com/oasisprotocol/oasis-core/go/common/cbor"
+	"github.com/oasisprotocol/oasis-core/go/common/errors"
+)
+
+const moduleName = "grpc"
+
+// limiterPruneInterval is the minimum interval between pruning idle peer buckets.
+const limiterPruneInterval = time.Minute
+
+var (
+	// ErrRateLimited is the error returned when a request is rejected due to rate limiting.
+	ErrRateLimited = errors.New(moduleName, 1, "grpc: rate limit exceeded")
+	// ErrTooManyRequests is the error returned when a request is rejected because the maximum
+	// number of concurrent requests has been reached.
+	ErrTooManyRequests = errors.New(moduleName, 2, "grpc: too many concurrent requests")
+)
+
+// LimitsConfig is the rate limiting and admission control configuration of a server.
+//
+// Zero values disable the corresponding limit.
+type LimitsConfig struct {
+	// PeerRate is the number of requests per second that a single peer is allowed to make.
+	PeerRate float64
+	// PeerBurst is the maximum number of requests that a single peer can make in a burst.
+	PeerBurst int
+	// GlobalRate is the number of requests per second that all peers together are allowed to make.
+	GlobalRate float64
+	// GlobalBurst is the maximum number of requests that all peers together can make in a burst.
+	GlobalBurst int
+	// MaxConcurrentRequests is the maximum number of requests (including streams) that are being
+	// handled concurrently.
+	MaxConcurrentRequests int
+	// MaxConcurrentStreams is the maximum number of concurrent streams on a single connection.
+	MaxConcurrentStreams uint32
+}
+
+type tokenBucket struct {
+	tokens  float64
+	updated time.Time
+}
+
+func (b *tokenBucket) refill(now time.Time, rate, burst float64) {
+	b.tokens += now.Sub(b.updated).Seconds() * rate
+	if b.tokens > burst {
+		b.tokens = burst
+	}
+	b.updated = now
+}
+
+// limiter implements rate limiting and admission control for gRPC requests.
+type limiter struct {
+	mu sync.Mutex
+
+	cfg LimitsConfig
+
+	global     *tokenBucket
+	peers      map[string]*tokenBucket
+	lastPruned time.Time
+	inflight   int
+
+	now func() time.Time
+}
+
+func newLimiter(cfg *LimitsConfig) *limiter {
+	l := &limiter{
+		cfg:   *cfg,
+		peers: make(map[string]*tokenBucket),
+		now:   time.Now,
+	}
+	// Always allow at least a single request.
+	if l.cfg.PeerBurst < 1 {
+		l.cfg.PeerBurst = 1
+	}
+	if l.cfg.GlobalBurst < 1 {
+		l.cfg.GlobalBurst = 1
+	}
+	if l.cfg.GlobalRate > 0 {
+		l.global = &tokenBucket{tokens: float64(l.cfg.GlobalBurst), updated: l.now()}
+	}
+	return l
+}
+
+// acquire attempts to admit a request from the given peer. In case the request is admitted, the
+// caller must call release once the request has been handled.
+func (l *limiter) acquire(peerKey string) error {
+	l.mu.Lock()
+	defer l.mu.Unlock()
+
+	if l.cfg.MaxConcurrentRequests > 0 && l.inflight >= l.cfg.MaxConcurrentRequests {
+		return ErrTooManyRequests
+	}
+
+	// Check all buckets before taking tokens so that rejected requests do not consume tokens.
+	now := l.now()
+	var peerBucket *tokenBucket
+	if l.cfg.PeerRate > 0 {
+		if now.Sub(l.lastPruned) >= limiterPruneInterval {
+			l.prune(now)
+		}
+
+		var ok bool
+		peerBucket, ok = l.peers[peerKey]
+		if !ok {
+			peerBucket = &tokenBucket{tokens: float64(l.cfg.PeerBurst), updated: now}
+			l.peers[peerKey] = peerBucket
+		}
+		peerBucket.refill(now, l.cfg.PeerRate, float64(l.cfg.PeerBurst))
+		if peerBucket.tokens < 1 {
+			return ErrRateLimited
+		}
+	}
+	if l.global != nil {
+		l.global.refill(now, l.cfg.GlobalRate, float64(l.cfg.GlobalBurst))
+		if l.global.tokens < 1 {
+			return ErrRateLimited
+		}
+		l.global.tokens--
+	}
+	if peerBucket != nil {
+		peerBucket.tokens--
+	}
+
+	l.inflight++
+	return nil
+}
+
+func (l *limiter) release() {
+	l.mu.Lock()
+	defer l.mu.Unlock()
+
+	l.inflight--
+}
+
+// prune removes peer buckets that have been fully replenished and carry no state.
+func (l *limiter) prune(now time.Time) {
+	burst := float64(l.cfg.PeerBurst)
+	for key, b := range l.peers {
+		if b.tokens+now.Sub(b.updated).Seconds()*l.cfg.PeerRate >= burst {
+			delete(l.peers, key)
+		}
+	}
+	l.lastPruned = now
+}
+
+func (l *limiter) admit(ctx context.Context) error {
+	if err := l.acquire(peerKeyFromContext(ctx)); err != nil {
+		return resourceExhaustedError(err)
+	}
+	return nil
+}
+
+func (l *limiter) unaryInterceptor(
+	ctx context.Context,
+	req interface{},
+	_ *grpc.UnaryServerInfo,
+	handler grpc.UnaryHandler,
+) (interface{}, error) {
+	if err := l.admit(ctx); err != nil {
+		return nil, err
+	}
+	defer l.release()
+
+	return handler(ctx, req)
+}
+
+func (l *limiter) streamInterceptor(
+	srv interface{},
+	ss grpc.ServerStream,
+	_ *grpc.StreamServerInfo,
+	handler grpc.StreamHandler,
+) error {
+	if err := l.admit(ss.Context()); err != nil {
+		return err
+	}
+	defer l.release()
+
+	return handler(srv, ss)
+}
+
+// peerKeyFromContext returns the key used to identify the remote peer for rate limiting.
+func peerKeyFromContext(ctx context.Context) string {
+	p, ok := peer.FromContext(ctx)
+	if !ok || p.Addr == nil {
+		return ""
+	}
+
+	addr := p.Addr.String()
+	if host, _, err := net.SplitHostPort(addr); err == nil {
+		return host
+	}
+	return addr
+}
+
+// resourceExhaustedError converts the given error into a gRPC error with the RESOURCE_EXHAUSTED
+// code, while preserving the module and code so that clients can map it back.
+func resourceExhaustedError(err error) error {
+	module, code := errors.Code(err)
+
+	return status.FromProto(&spb.Status{
+		Code:    int32(codes.ResourceExhausted),
+		Message: err.Error(),
+		Details: []*any.Any{
+			{
+				Value: cbor.Marshal(&grpcError{Module: module, Code: code}),
+			},
+		},
+	}).Err()
+}
@@ -0,0 +1,63 @@
+package grpc
+
+import (
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+	"google.golang.org/grpc/codes"
+
+	"github.com/oasisprotocol/oasis-core/go/common/errors"
+)
+
+func TestLimiter(t *testing.T) {
+	require := require.New(t)
+
+	now := time.Unix(1_000_000, 0)
+	l := newLimiter(&LimitsConfig{
+		PeerRate:              1,
+		PeerBurst:             2,
+		GlobalRate:            10,
+		GlobalBurst:           3,
+		MaxConcurrentRequests: 4,
+	})
+	l.now = func() time.Time { return now }
+
+	// Per-peer burst.
+	require.NoError(l.acquire("a"))
+	require.NoError(l.acquire("a"))
+	require.ErrorIs(l.acquire("a"), ErrRateLimited, "peer burst should be exhausted")
+
+	// Global burst.
+	require.NoError(l.acquire("b"))
+	require.ErrorIs(l.acquire("c"), ErrRateLimited, "global burst should be exhausted")
+	require.EqualValues(2, l.peers["c"].tokens, "rejected requests should not consume peer tokens")
+
+	// Concurrency limit.
+	now = now.Add(time.Second)
+	require.NoError(l.acquire("d"))
+	require.ErrorIs(l.acquire("e"), ErrTooManyRequests, "concurrency limit should be reached")
+	l.release()
+	require.NoError(l.acquire("e"))
+
+	// Buckets should be replenished over time and pruned.
+	for i := 0; i < 4; i++ {
+		l.release()
+	}
+	now = now.Add(10 * time.Second)
+	require.NoError(l.acquire("a"))
+	require.Len(l.peers, 5, "buckets should not be pruned before the prune interval")
+	now = now.Add(limiterPruneInterval)
+	require.NoError(l.acquire("a"))
+	require.Len(l.peers, 1, "replenished buckets should be pruned")
+}
+
+func TestResourceExhaustedError(t *testing.T) {
+	require := require.New(t)
+
+	err := resourceExhaustedError(ErrRateLimited)
+	require.True(IsErrorCode(err, codes.ResourceExhausted), "error should have the RESOURCE_EXHAUSTED code")
+
+	mapped := errorFromGrpc(err)
+	require.True(errors.Is(mapped, ErrRateLimited), "error should map back to ErrRateLimited")
+}
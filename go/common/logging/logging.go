@@ -11,6 +11,7 @@ import (
 	"sort"
 	"strings"
 	"sync"
+	"sync/atomic"
 
 	"github.com/go-kit/log"
 	"github.com/go-kit/log/level"
@@ -83,21 +84,6 @@ const (
 	LevelError
 )
 
-func (l Level) toOption() level.Option {
-	switch l {
-	case LevelDebug:
-		return level.AllowDebug()
-	case LevelInfo:
-		return level.AllowInfo()
-	case LevelWarn:
-		return level.AllowWarn()
-	case LevelError:
-		return level.AllowError()
-	default:
-		panic("logging: unsupported log level")
-	}
-}
-
 // String returns the string representation of a Level.
 func (l *Level) String() string {
 	switch *l {
@@ -140,13 +126,26 @@ func (l *Level) Type() string {
 // Logger is a logger instance.
 type Logger struct {
 	logger log.Logger
-	level  Level
+	level  atomic.Uint32
+	gen    atomic.Uint64
 	module string
 }
 
+// getLevel returns the logger's level, re-evaluating it in case the
+// log levels have been changed since it was last evaluated.
+func (l *Logger) getLevel() Level {
+	if gen := backend.generation.Load(); gen != l.gen.Load() {
+		backend.Lock()
+		backend.setupLogLevelLocked(l)
+		l.gen.Store(gen)
+		backend.Unlock()
+	}
+	return Level(l.level.Load())
+}
+
 // Debug logs the message and key value pairs at the Debug log level.
 func (l *Logger) Debug(msg string, keyvals ...interface{}) {
-	if l.level > LevelDebug {
+	if l.getLevel() > LevelDebug {
 		return
 	}
 	keyvals = append([]interface{}{"msg", msg}, keyvals...)
@@ -155,7 +154,7 @@ func (l *Logger) Debug(msg string, keyvals ...interface{}) {
 
 // Info logs the message and key value pairs at the Info log level.
 func (l *Logger) Info(msg string, keyvals ...interface{}) {
-	if l.level > LevelInfo {
+	if l.getLevel() > LevelInfo {
 		return
 	}
 	keyvals = append([]interface{}{"msg", msg}, keyvals...)
@@ -164,7 +163,7 @@ func (l *Logger) Info(msg string, keyvals ...interface{}) {
 
 // Warn logs the message and key value pairs at the Warn log level.
 func (l *Logger) Warn(msg string, keyvals ...interface{}) {
-	if l.level > LevelWarn {
+	if l.getLevel() > LevelWarn {
 		return
 	}
 	keyvals = append([]interface{}{"msg", msg}, keyvals...)
@@ -173,7 +172,7 @@ func (l *Logger) Warn(msg string, keyvals ...interface{}) {
 
 // Error logs the message and key value pairs at the Error log level.
 func (l *Logger) Error(msg string, keyvals ...interface{}) {
-	if l.level > LevelError {
+	if l.getLevel() > LevelError {
 		return
 	}
 	keyvals = append([]interface{}{"msg", msg}, keyvals...)
@@ -183,10 +182,13 @@ func (l *Logger) Error(msg string, keyvals ...interface{}) {
 // With returns a clone of the logger with the provided key/value pairs
 // added via log.WithPrefix.
 func (l *Logger) With(keyvals ...interface{}) *Logger {
-	return &Logger{
+	nl := &Logger{
 		logger: log.With(l.logger, keyvals...),
-		level:  l.level,
+		module: l.module,
 	}
+	nl.level.Store(l.level.Load())
+	nl.gen.Store(l.gen.Load())
+	return nl
 }
 
 // NewNopLogger creates a logger that doesn't perform any logging.
@@ -198,9 +200,30 @@ func NewNopLogger() *Logger {
 
 // GetLevel returns the current global log level.
 func GetLevel() Level {
+	backend.Lock()
+	defer backend.Unlock()
+
 	return backend.defaultLevel
 }
 
+// SetLevels changes the default and per-module log levels of an
+// initialized logging backend. Existing loggers pick up the new levels
+// the next time they are used.
+func SetLevels(defaultLvl Level, moduleLvls map[string]Level) error {
+	backend.Lock()
+	defer backend.Unlock()
+
+	if !backend.initialized {
+		return fmt.Errorf("logging: not initialized")
+	}
+
+	backend.defaultLevel = defaultLvl
+	backend.moduleLevels = moduleLvls
+	backend.generation.Add(1)
+
+	return nil
+}
+
 // GetLogger creates a new logger instance with the specified module.
 //
 // This may be called from any point, including before Initialize is
@@ -236,7 +259,9 @@ func GetBaseLogger(module string) *Logger {
 // Initialize initializes the logging backend to write to the provided
 // Writer with the given format and log levels specified for each
 // module. If the requested module is not given, default level is
-// taken. If the Writer is nil, all log output will be silently discarded.
+// taken. Module levels are not limited by the default level, so a
+// module may log more verbosely than the default. If the Writer is nil,
+// all log output will be silently discarded.
 func Initialize(w io.Writer, format Format, defaultLvl Level, moduleLvls map[string]Level) error {
 	backend.Lock()
 	defer backend.Unlock()
@@ -258,8 +283,6 @@ func Initialize(w io.Writer, format Format, defaultLvl Level, moduleLvls map[str
 		}
 	}
 
-	logger = level.NewFilter(logger, defaultLvl.toOption())
-
 	backend.baseLogger = logger
 	backend.moduleLevels = moduleLvls
 	backend.defaultLevel = defaultLvl
@@ -303,6 +326,7 @@ type logBackend struct {
 	earlyLoggers []*earlyLogger
 	defaultLevel Level
 	moduleLevels map[string]Level
+	generation   atomic.Uint64
 
 	initialized bool
 }
@@ -325,7 +349,7 @@ func (b *logBackend) setupLogLevelLocked(l *Logger) {
 		}
 	}
 
-	l.level = lvl
+	l.level.Store(uint32(lvl))
 }
 
 func (b *logBackend) getLogger(module string, extraUnwind int) *Logger {
@@ -0,0 +1,42 @@
+package logging
+
+import (
+	"bytes"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestSetLevels(t *testing.T) {
+	require := require.New(t)
+
+	err := SetLevels(LevelDebug, nil)
+	require.Error(err, "SetLevels should fail before initialization")
+
+	var buf bytes.Buffer
+	err = Initialize(&buf, FmtLogfmt, LevelError, nil)
+	require.NoError(err, "Initialize")
+
+	module := GetLogger("test/module")
+	other := GetLogger("test/other")
+
+	module.Debug("debug before")
+	require.NotContains(buf.String(), "debug before", "messages below the level should be filtered")
+
+	err = SetLevels(LevelWarn, map[string]Level{"test/module": LevelDebug})
+	require.NoError(err, "SetLevels")
+
+	module.Debug("debug after")
+	require.Contains(buf.String(), "debug after", "lowered module level should apply to existing loggers")
+
+	other.Info("info other")
+	require.NotContains(buf.String(), "info other", "messages below the default level should be filtered")
+	other.Warn("warn other")
+	require.Contains(buf.String(), "warn other", "raised default level should apply to existing loggers")
+
+	err = SetLevels(LevelError, nil)
+	require.NoError(err, "SetLevels")
+
+	module.Warn("warn module")
+	require.NotContains(buf.String(), "warn module", "raised levels should apply to existing loggers")
+}
@@ -162,24 +162,34 @@ func DefaultConfig() Config {
 
 // InitConfig initializes the global configuration from the given file.
 func InitConfig(cfgFile string) error {
+	cfg, err := loadConfig(cfgFile)
+	GlobalConfig = *cfg
+	return err
+}
+
+// loadConfig loads and validates the configuration from the given file.
+//
+// The returned configuration is never nil, even in case of errors.
+func loadConfig(cfgFile string) (*Config, error) {
+	cfg := DefaultConfig()
+
 	// Read the specified config file and substitute environment variables.
-	cfg, err := envsubst.ReadFile(cfgFile)
+	raw, err := envsubst.ReadFile(cfgFile)
 	if err != nil {
-		return fmt.Errorf("unable to read config file '%s': %w", cfgFile, err)
+		return &cfg, fmt.Errorf("unable to read config file '%s': %w", cfgFile, err)
 	}
 
-	// Reset the global config and apply changes from the config file.
+	// Apply changes from the config file to the default config.
 	// Report error if any of the fields from the input file are unknown.
-	GlobalConfig = DefaultConfig()
-	dec := yaml.NewDecoder(bytes.NewReader(cfg))
+	dec := yaml.NewDecoder(bytes.NewReader(raw))
 	dec.KnownFields(true)
-	err = dec.Decode(&GlobalConfig)
+	err = dec.Decode(&cfg)
 	if err != nil && err != io.EOF {
-		return fmt.Errorf("failed to load config file '%s': %w", cfgFile, err)
+		return &cfg, fmt.Errorf("failed to load config file '%s': %w", cfgFile, err)
 	}
 
 	// Validate config file.
-	return GlobalConfig.Validate()
+	return &cfg, cfg.Validate()
 }
 
 func init() {
@@ -0,0 +1,34 @@
+package config
+
+import (
+	"github.com/oasisprotocol/oasis-core/go/common/pubsub"
+)
+
+var reloadNotifier = pubsub.NewBroker(false)
+
+// WatchConfig returns a channel that produces a stream of configurations
+// reloaded via ReloadConfig.
+//
+// Components that support changing some of their settings at runtime
+// should subscribe and apply the relevant values. Note that the global
+// configuration is not updated on reload.
+func WatchConfig() (<-chan *Config, pubsub.ClosableSubscription) {
+	typedCh := make(chan *Config)
+	sub := reloadNotifier.Subscribe()
+	sub.Unwrap(typedCh)
+
+	return typedCh, sub
+}
+
+// ReloadConfig loads and validates the configuration from the given file
+// and notifies all watchers in case it is valid.
+func ReloadConfig(cfgFile string) (*Config, error) {
+	cfg, err := loadConfig(cfgFile)
+	if err != nil {
+		return nil, err
+	}
+
+	reloadNotifier.Broadcast(cfg)
+
+	return cfg, nil
+}
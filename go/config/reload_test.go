@@ -0,0 +1,39 @@
+package config
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestReloadConfig(t *testing.T) {
+	require := require.New(t)
+
+	cfgFile := filepath.Join(t.TempDir(), "config.yml")
+
+	cfgCh, sub := WatchConfig()
+	defer sub.Close()
+
+	// Invalid configurations should not be broadcast.
+	err := os.WriteFile(cfgFile, []byte("mode: invalid\n"), 0o600)
+	require.NoError(err, "WriteFile")
+	_, err = ReloadConfig(cfgFile)
+	require.Error(err, "ReloadConfig should fail for invalid configuration")
+
+	err = os.WriteFile(cfgFile, []byte("common:\n  log:\n    level:\n      default: debug\n"), 0o600)
+	require.NoError(err, "WriteFile")
+	cfg, err := ReloadConfig(cfgFile)
+	require.NoError(err, "ReloadConfig")
+	require.Equal("debug", cfg.Common.Log.Level["default"])
+
+	select {
+	case watched := <-cfgCh:
+		require.Equal(cfg, watched, "watchers should receive the reloaded configuration")
+	case <-time.After(time.Second):
+		t.Fatalf("failed to receive reloaded configuration")
+	}
+	require.Empty(GlobalConfig.Common.Log.Level, "global configuration should not be updated")
+}
@@ -16,6 +16,7 @@ import (
 	"github.com/cometbft/cometbft/store"
 	cmttypes "github.com/cometbft/cometbft/types"
 
+	"github.com/oasisprotocol/oasis-core/go/beacon"
 	beaconAPI "github.com/oasisprotocol/oasis-core/go/beacon/api"
 	"github.com/oasisprotocol/oasis-core/go/common/cbor"
 	"github.com/oasisprotocol/oasis-core/go/common/crypto/signature"
@@ -198,6 +199,9 @@ func (n *commonNode) initialize() error {
 		return err
 	}
 	n.beacon = scBeacon
+	if cmmetrics.Enabled() {
+		n.svcMgr.RegisterCleanupOnly(beacon.NewMetricsUpdater(n.ctx, n.beacon), "beacon metrics updater")
+	}
 	n.serviceClients = append(n.serviceClients, scBeacon)
 	if err = n.mux.SetEpochtime(n.beacon); err != nil {
 		return err
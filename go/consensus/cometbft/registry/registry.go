@@ -157,7 +157,18 @@ func (sc *serviceClient) GetRuntimes(ctx context.Context, query *api.GetRuntimes
 	if err != nil {
 		return nil, err
 	}
-	return q.Runtimes(ctx, query.IncludeSuspended)
+	runtimes, err := q.Runtimes(ctx, query.IncludeSuspended)
+	if err != nil {
+		return nil, err
+	}
+
+	filtered := make([]*api.Runtime, 0, len(runtimes))
+	for _, rt := range runtimes {
+		if query.Matches(rt) {
+			filtered = append(filtered, rt)
+		}
+	}
+	return filtered, nil
 }
 
 func (sc *serviceClient) StateToGenesis(ctx context.Context, height int64) (*api.Genesis, error) {
@@ -10,6 +10,7 @@ import (
 	cmttypes "github.com/cometbft/cometbft/types"
 	"github.com/eapache/channels"
 
+	"github.com/oasisprotocol/oasis-core/go/common/crypto/signature"
 	"github.com/oasisprotocol/oasis-core/go/common/logging"
 	"github.com/oasisprotocol/oasis-core/go/common/pubsub"
 	consensus "github.com/oasisprotocol/oasis-core/go/consensus/api"
@@ -30,8 +31,9 @@ type serviceClient struct {
 
 	logger *logging.Logger
 
-	querier  *app.QueryFactory
-	notifier *pubsub.Broker
+	querier           *app.QueryFactory
+	notifier          *pubsub.Broker
+	validatorNotifier *pubsub.Broker
 }
 
 func (sc *serviceClient) StateToGenesis(ctx context.Context, height int64) (*api.Genesis, error) {
@@ -91,6 +93,50 @@ func (sc *serviceClient) WatchCommittees(_ context.Context) (<-chan *api.Committ
 	return typedCh, sub, nil
 }
 
+func (sc *serviceClient) WatchNodeCommittees(ctx context.Context, nodeID signature.PublicKey) (<-chan *api.Committee, pubsub.ClosableSubscription, error) {
+	typedCh := make(chan *api.Committee)
+	notifySub := sc.notifier.Subscribe()
+	notifySub.Unwrap(typedCh)
+
+	ctx, sub := pubsub.NewContextSubscription(ctx)
+	ch := make(chan *api.Committee)
+
+	go func() {
+		defer close(ch)
+		defer notifySub.Close()
+
+		for {
+			select {
+			case c, ok := <-typedCh:
+				if !ok {
+					return
+				}
+				if !c.IsMember(nodeID) {
+					continue
+				}
+
+				select {
+				case ch <- c:
+				case <-ctx.Done():
+					return
+				}
+			case <-ctx.Done():
+				return
+			}
+		}
+	}()
+
+	return ch, sub, nil
+}
+
+func (sc *serviceClient) WatchValidators(_ context.Context) (<-chan []*api.Validator, pubsub.ClosableSubscription, error) {
+	typedCh := make(chan []*api.Validator)
+	sub := sc.validatorNotifier.Subscribe()
+	sub.Unwrap(typedCh)
+
+	return typedCh, sub, nil
+}
+
 func (sc *serviceClient) getCurrentCommittees() ([]*api.Committee, error) {
 	q, err := sc.querier.QueryAt(context.TODO(), consensus.HeightLatest)
 	if err != nil {
@@ -136,6 +182,17 @@ func (sc *serviceClient) DeliverEvent(ctx context.Context, height int64, _ cmtty
 			for _, c := range committees {
 				sc.notifier.Broadcast(c)
 			}
+
+			// Validators are elected together with committees and the new validator set is
+			// stashed at the end of the same block.
+			validators, err := q.Validators(ctx)
+			if err != nil {
+				sc.logger.Error("worker: couldn't query elected validators",
+					"err", err,
+				)
+				continue
+			}
+			sc.validatorNotifier.Broadcast(validators)
 		}
 	}
 	return nil
@@ -165,6 +222,23 @@ func New(backend tmapi.Backend) (ServiceClient, error) {
 			ch.In() <- c
 		}
 	})
+	sc.validatorNotifier = pubsub.NewBrokerEx(func(ch channels.Channel) {
+		q, err := sc.querier.QueryAt(context.TODO(), consensus.HeightLatest)
+		if err != nil {
+			sc.logger.Error("couldn't get current validators, won't send them",
+				"err", err,
+			)
+			return
+		}
+		validators, err := q.Validators(context.TODO())
+		if err != nil {
+			sc.logger.Error("couldn't get current validators, won't send them",
+				"err", err,
+			)
+			return
+		}
+		ch.In() <- validators
+	})
 
 	return sc, nil
 }
@@ -83,5 +83,6 @@ func NewServer(
 			lc:        lightClient,
 			logger:    logging.GetLogger("consensus/p2p/light/server"),
 		},
+		rpc.ServerOptionsFromConfig()...,
 	)
 }
@@ -134,10 +134,35 @@ func TestGenesisChainContext(t *testing.T) {
 	stableDoc.Staking = staking.Genesis{}
 
 	// Having to update this every single time the genesis structure
-	// changes isn't annoying at all.
+	// changes isn't annoying at all. This is also used as a test vector
+	// by the Rust genesis document implementation.
 	require.Equal(t, "a9dfaa6890772f10bde7b0c34214b1faf22fdecd334d2199f30414f3a30aa3f6", stableDoc.ChainContext())
 }
 
+func TestGenesisChainContextDefaults(t *testing.T) {
+	// Ensure that the chain context of a document with zero-value sections is stable. This is
+	// also used as a test vector by the Rust genesis document implementation.
+	doc := genesis.Document{
+		Height:  1,
+		ChainID: "test-chain",
+		Time:    time.Unix(1_600_000_000, 0),
+		Staking: staking.Genesis{
+			TokenSymbol:        "TEST",
+			TokenValueExponent: 6,
+			TotalSupply:        *quantity.NewFromUint64(1000),
+			CommonPool:         *quantity.NewFromUint64(1000),
+		},
+		Consensus: consensus.Genesis{
+			Backend: cmt.BackendName,
+			Parameters: consensus.Parameters{
+				TimeoutCommit: 1 * time.Millisecond,
+			},
+		},
+	}
+
+	require.Equal(t, "844fd0cc98c059e1d24d342926a98f1151ffaa6cb994694fae73646a0f4570e6", doc.ChainContext())
+}
+
 func TestGenesisSanityCheck(t *testing.T) {
 	viper.Set(cmdFlags.CfgDebugDontBlameOasis, true)
 	require := require.New(t)
@@ -13,7 +13,10 @@ import (
 	"github.com/oasisprotocol/oasis-core/go/common/service"
 )
 
-const svcStopTimeout = 10 * time.Second
+const (
+	svcStopTimeout     = 10 * time.Second
+	defaultStopTimeout = 60 * time.Second
+)
 
 // ServiceManager manages a group of background services.
 type ServiceManager struct {
@@ -25,7 +28,8 @@ type ServiceManager struct {
 	termCh   chan service.BackgroundService
 	termSvc  service.BackgroundService
 
-	stopCh chan struct{}
+	stopCh      chan struct{}
+	stopTimeout time.Duration
 }
 
 // Register registers a background service.
@@ -45,8 +49,23 @@ func (m *ServiceManager) RegisterCleanupOnly(svc service.CleanupAble, name strin
 	m.services = append(m.services, service.NewCleanupOnlyService(svc, name))
 }
 
+// SetStopTimeout sets the maximum amount of time that is spent waiting
+// for all services to stop, after which any remaining services are
+// stopped without waiting for them to terminate.
+func (m *ServiceManager) SetStopTimeout(timeout time.Duration) {
+	m.stopTimeout = timeout
+}
+
 // Wait waits for interruption via Stop, SIGINT, SIGTERM, or any of
 // the registered services to terminate, and stops all services.
+//
+// Services are stopped in reverse registration order, waiting for each
+// to terminate before stopping the next one, so that services are
+// drained before the services they depend on.
+//
+// As services are stopped sequentially, the total shutdown latency is the
+// sum of the individual stop times. Once the stop timeout expires, the
+// remaining services are still stopped, but are no longer waited for.
 func (m *ServiceManager) Wait() {
 	sigCh := make(chan os.Signal, 1)
 	signal.Notify(sigCh, os.Interrupt, syscall.SIGTERM)
@@ -64,16 +83,17 @@ func (m *ServiceManager) Wait() {
 	m.cancelFn()
 
 	m.logger.Debug("stopping services")
-	for _, svc := range m.services {
+	deadline := time.After(m.stopTimeout)
+	var aborted bool
+	for i := len(m.services) - 1; i >= 0; i-- {
+		svc := m.services[i]
 		if svc != m.termSvc {
 			m.logger.Debug("stopping service",
 				"svc", svc.Name(),
 			)
 			svc.Stop()
 		}
-	}
-	for _, svc := range m.services {
-		if service.IsCleanupOnlyService(svc) {
+		if aborted || service.IsCleanupOnlyService(svc) {
 			continue
 		}
 
@@ -86,6 +106,11 @@ func (m *ServiceManager) Wait() {
 			m.logger.Warn("timed out waiting for the service to stop",
 				"svc", svc.Name(),
 			)
+		case <-deadline:
+			m.logger.Warn("timed out waiting for services to stop, aborting",
+				"svc", svc.Name(),
+			)
+			aborted = true
 		}
 	}
 	m.logger.Debug("all services stopped")
@@ -97,11 +122,13 @@ func (m *ServiceManager) Stop() {
 	m.cancelFn()
 }
 
-// Cleanup cleans up after all registered services.
+// Cleanup cleans up after all registered services in reverse
+// registration order.
 func (m *ServiceManager) Cleanup() {
 	m.logger.Debug("beginning cleanup")
 
-	for _, svc := range m.services {
+	for i := len(m.services) - 1; i >= 0; i-- {
+		svc := m.services[i]
 		m.logger.Debug("cleaning up",
 			"svc", svc.Name(),
 		)
@@ -116,10 +143,11 @@ func NewServiceManager(logger *logging.Logger) *ServiceManager {
 	ctx, cancelFn := context.WithCancel(context.Background())
 
 	return &ServiceManager{
-		Ctx:      ctx,
-		cancelFn: cancelFn,
-		logger:   logger,
-		termCh:   make(chan service.BackgroundService),
-		stopCh:   make(chan struct{}),
+		Ctx:         ctx,
+		cancelFn:    cancelFn,
+		logger:      logger,
+		termCh:      make(chan service.BackgroundService),
+		stopCh:      make(chan struct{}),
+		stopTimeout: defaultStopTimeout,
 	}
 }
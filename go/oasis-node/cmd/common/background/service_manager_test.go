@@ -0,0 +1,110 @@
+package background
+
+import (
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+
+	"github.com/oasisprotocol/oasis-core/go/common/logging"
+)
+
+type eventLog struct {
+	sync.Mutex
+
+	events []string
+}
+
+func (l *eventLog) add(event string) {
+	l.Lock()
+	defer l.Unlock()
+
+	l.events = append(l.events, event)
+}
+
+func (l *eventLog) get() []string {
+	l.Lock()
+	defer l.Unlock()
+
+	return append([]string{}, l.events...)
+}
+
+type fakeService struct {
+	name   string
+	log    *eventLog
+	hang   bool
+	quitCh chan struct{}
+	once   sync.Once
+}
+
+func (s *fakeService) Name() string {
+	return s.name
+}
+
+func (s *fakeService) Start() error {
+	return nil
+}
+
+func (s *fakeService) Stop() {
+	s.log.add("stop " + s.name)
+	if s.hang {
+		return
+	}
+	s.once.Do(func() {
+		close(s.quitCh)
+	})
+}
+
+func (s *fakeService) Quit() <-chan struct{} {
+	return s.quitCh
+}
+
+func (s *fakeService) Cleanup() {
+	s.log.add("cleanup " + s.name)
+}
+
+func newFakeService(name string, log *eventLog, hang bool) *fakeService {
+	return &fakeService{
+		name:   name,
+		log:    log,
+		hang:   hang,
+		quitCh: make(chan struct{}),
+	}
+}
+
+func TestServiceManagerOrder(t *testing.T) {
+	require := require.New(t)
+
+	var log eventLog
+	m := NewServiceManager(logging.GetLogger("test"))
+	m.Register(newFakeService("a", &log, false))
+	m.Register(newFakeService("b", &log, false))
+	m.Register(newFakeService("c", &log, false))
+
+	m.Stop()
+	m.Wait()
+	m.Cleanup()
+
+	require.Equal([]string{
+		"stop c", "stop b", "stop a",
+		"cleanup c", "cleanup b", "cleanup a",
+	}, log.get(), "services should be stopped and cleaned up in reverse order")
+}
+
+func TestServiceManagerStopTimeout(t *testing.T) {
+	require := require.New(t)
+
+	var log eventLog
+	m := NewServiceManager(logging.GetLogger("test"))
+	m.SetStopTimeout(100 * time.Millisecond)
+	m.Register(newFakeService("a", &log, false))
+	m.Register(newFakeService("b", &log, true))
+
+	m.Stop()
+	start := time.Now()
+	m.Wait()
+	require.Less(time.Since(start), svcStopTimeout, "stop timeout should abort waiting")
+
+	require.Equal([]string{"stop b", "stop a"}, log.get(), "remaining services should be stopped")
+}
@@ -1,12 +1,19 @@
 // Package config implements global configuration options.
 package config
 
+import (
+	"fmt"
+	"time"
+)
+
 // Config is the common configuration structure.
 type Config struct {
 	// Node's data directory.
 	DataDir string `yaml:"data_dir"`
 	// Path to the node's internal unix socket.
 	InternalSocketPath string `yaml:"internal_socket_path,omitempty"`
+	// Maximum amount of time spent waiting for services to stop on shutdown.
+	StopTimeout time.Duration `yaml:"stop_timeout,omitempty"`
 	// Logging configuration options.
 	Log LogConfig `yaml:"log,omitempty"`
 	// Debug configuration options (do not use).
@@ -20,6 +27,9 @@ type LogConfig struct {
 	// Log format (logfmt, json).
 	Format string `yaml:"format,omitempty"`
 	// Log level (debug, info, warn, error) per module.
+	//
+	// The "default" entry applies to modules without their own level. Module levels may be
+	// more verbose than the default level.
 	Level map[string]string `yaml:"level,omitempty"`
 }
 
@@ -33,13 +43,18 @@ type DebugConfig struct {
 
 // Validate validates the configuration settings.
 func (c *Config) Validate() error {
+	if c.StopTimeout <= 0 {
+		return fmt.Errorf("stop_timeout must be greater than zero")
+	}
+
 	return nil
 }
 
 // DefaultConfig returns the default configuration settings.
 func DefaultConfig() Config {
 	return Config{
-		DataDir: "",
+		DataDir:     "",
+		StopTimeout: 60 * time.Second,
 		Log: LogConfig{
 			File:   "",
 			Format: "logfmt",
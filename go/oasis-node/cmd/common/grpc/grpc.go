@@ -23,6 +23,19 @@ import (
 const (
 	// CfgServerPort configures the server port.
 	CfgServerPort = "grpc.port"
+	// CfgServerPeerRate configures the number of requests per second a single peer can make.
+	CfgServerPeerRate = "grpc.limits.peer_rate"
+	// CfgServerPeerBurst configures the number of requests a single peer can make in a burst.
+	CfgServerPeerBurst = "grpc.limits.peer_burst"
+	// CfgServerGlobalRate configures the number of requests per second all peers can make.
+	CfgServerGlobalRate = "grpc.limits.global_rate"
+	// CfgServerGlobalBurst configures the number of requests all peers can make in a burst.
+	CfgServerGlobalBurst = "grpc.limits.global_burst"
+	// CfgServerMaxConcurrentRequests configures the maximum number of concurrent requests.
+	CfgServerMaxConcurrentRequests = "grpc.limits.max_concurrent_requests"
+	// CfgServerMaxConcurrentStreams configures the maximum number of concurrent streams per
+	// connection.
+	CfgServerMaxConcurrentStreams = "grpc.limits.max_concurrent_streams"
 	// CfgAddress configures the remote address.
 	CfgAddress = "address"
 	// CfgWait waits for the remote address to become available.
@@ -53,6 +66,14 @@ func NewServerTCP(cert *tls.Certificate, installWrapper bool) (*cmnGrpc.Server,
 		Port:           uint16(viper.GetInt(CfgServerPort)),
 		Identity:       identity.WithTLSCertificate(cert),
 		InstallWrapper: installWrapper,
+		Limits: &cmnGrpc.LimitsConfig{
+			PeerRate:              viper.GetFloat64(CfgServerPeerRate),
+			PeerBurst:             viper.GetInt(CfgServerPeerBurst),
+			GlobalRate:            viper.GetFloat64(CfgServerGlobalRate),
+			GlobalBurst:           viper.GetInt(CfgServerGlobalBurst),
+			MaxConcurrentRequests: viper.GetInt(CfgServerMaxConcurrentRequests),
+			MaxConcurrentStreams:  viper.GetUint32(CfgServerMaxConcurrentStreams),
+		},
 	}
 	return cmnGrpc.NewServer(config)
 }
@@ -104,6 +125,12 @@ func NewClient(cmd *cobra.Command) (*grpc.ClientConn, error) {
 
 func init() {
 	ServerTCPFlags.Uint16(CfgServerPort, 9001, "gRPC server port")
+	ServerTCPFlags.Float64(CfgServerPeerRate, 0, "gRPC requests per second per peer (0 = unlimited)")
+	ServerTCPFlags.Int(CfgServerPeerBurst, 0, "gRPC request burst size per peer")
+	ServerTCPFlags.Float64(CfgServerGlobalRate, 0, "gRPC requests per second for all peers (0 = unlimited)")
+	ServerTCPFlags.Int(CfgServerGlobalBurst, 0, "gRPC request burst size for all peers")
+	ServerTCPFlags.Int(CfgServerMaxConcurrentRequests, 0, "maximum number of concurrent gRPC requests (0 = unlimited)")
+	ServerTCPFlags.Uint32(CfgServerMaxConcurrentStreams, 0, "maximum number of concurrent gRPC streams per connection (0 = default)")
 	_ = viper.BindPFlags(ServerTCPFlags)
 	ServerTCPFlags.AddFlagSet(cmnGrpc.Flags)
 
@@ -1,6 +1,7 @@
 package common
 
 import (
+	"fmt"
 	"io"
 	"os"
 
@@ -8,26 +9,33 @@ import (
 	"github.com/oasisprotocol/oasis-core/go/config"
 )
 
-func initLogging() error {
-	logFile := config.GlobalConfig.Common.Log.File
-
+func parseLogLevels(levels map[string]string) (logging.Level, map[string]logging.Level, error) {
 	logLevel := logging.LevelWarn
 	moduleLevels := map[string]logging.Level{}
-	var err error
-	for k, v := range config.GlobalConfig.Common.Log.Level {
+	for k, v := range levels {
 		if k == "default" {
-			if err = logLevel.Set(v); err != nil {
-				return err
+			if err := logLevel.Set(v); err != nil {
+				return 0, nil, err
 			}
 			continue
 		}
 
 		var lvl logging.Level
-		if err = lvl.Set(v); err != nil {
-			return err
+		if err := lvl.Set(v); err != nil {
+			return 0, nil, err
 		}
 		moduleLevels[k] = lvl
 	}
+	return logLevel, moduleLevels, nil
+}
+
+func initLogging() error {
+	logFile := config.GlobalConfig.Common.Log.File
+
+	logLevel, moduleLevels, err := parseLogLevels(config.GlobalConfig.Common.Log.Level)
+	if err != nil {
+		return err
+	}
 
 	logFmt := logging.FmtLogfmt
 	if config.GlobalConfig.Common.Log.Format != "" {
@@ -47,3 +55,22 @@ func initLogging() error {
 
 	return logging.Initialize(w, logFmt, logLevel, moduleLevels)
 }
+
+// ReloadConfig reloads the configuration file, applies the new log levels
+// and notifies any configuration watchers.
+func ReloadConfig() error {
+	if cfgFile == "" {
+		return fmt.Errorf("no config file")
+	}
+
+	cfg, err := config.ReloadConfig(cfgFile)
+	if err != nil {
+		return err
+	}
+
+	logLevel, moduleLevels, err := parseLogLevels(cfg.Common.Log.Level)
+	if err != nil {
+		return err
+	}
+	return logging.SetLevels(logLevel, moduleLevels)
+}
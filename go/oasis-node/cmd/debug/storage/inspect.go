@@ -0,0 +1,245 @@
+package storage
+
+import (
+	"context"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"os"
+
+	"github.com/spf13/cobra"
+	flag "github.com/spf13/pflag"
+	"github.com/spf13/viper"
+
+	"github.com/oasisprotocol/oasis-core/go/common"
+	control "github.com/oasisprotocol/oasis-core/go/control/api"
+	cmdCommon "github.com/oasisprotocol/oasis-core/go/oasis-node/cmd/common"
+	"github.com/oasisprotocol/oasis-core/go/oasis-node/cmd/common/flags"
+	cmdControl "github.com/oasisprotocol/oasis-core/go/oasis-node/cmd/control"
+	"github.com/oasisprotocol/oasis-core/go/roothash/api/block"
+	runtimeClient "github.com/oasisprotocol/oasis-core/go/runtime/client/api"
+	storageAPI "github.com/oasisprotocol/oasis-core/go/storage/api"
+	"github.com/oasisprotocol/oasis-core/go/storage/mkvs"
+	"github.com/oasisprotocol/oasis-core/go/storage/mkvs/node"
+)
+
+const (
+	cfgInspectRound    = "storage.inspect.round"
+	cfgInspectRootType = "storage.inspect.root_type"
+)
+
+var (
+	storageBlockCmd = &cobra.Command{
+		Use:   "block runtime-id (hex)",
+		Short: "show the runtime block at the given round",
+		Args:  validateRuntimeIDArgs(1),
+		Run:   doBlock,
+	}
+
+	storageGetCmd = &cobra.Command{
+		Use:   "get runtime-id (hex) key (hex)",
+		Short: "show the value stored under the given key at the given round (debug nodes only)",
+		Args:  validateRuntimeIDArgs(2),
+		Run:   doGet,
+	}
+
+	storageDumpCmd = &cobra.Command{
+		Use:   "dump runtime-id (hex)",
+		Short: "dump all keys and values of the storage root at the given round (debug nodes only)",
+		Args:  validateRuntimeIDArgs(1),
+		Run:   doDump,
+	}
+
+	storageInspectFlags = flag.NewFlagSet("", flag.ContinueOnError)
+)
+
+// keyValue is a pretty-printed storage entry.
+type keyValue struct {
+	Key   string `json:"key"`
+	Value string `json:"value"`
+}
+
+func validateRuntimeIDArgs(n int) cobra.PositionalArgs {
+	return func(cmd *cobra.Command, args []string) error {
+		if err := cobra.ExactArgs(n)(cmd, args); err != nil {
+			return err
+		}
+		if err := ValidateRuntimeIDStr(args[0]); err != nil {
+			return fmt.Errorf("malformed runtime id '%v': %w", args[0], err)
+		}
+		return nil
+	}
+}
+
+func parseRootType(s string) (node.RootType, error) {
+	switch s {
+	case "state":
+		return node.RootTypeState, nil
+	case "io":
+		return node.RootTypeIO, nil
+	default:
+		return node.RootTypeInvalid, fmt.Errorf("unknown root type: %s", s)
+	}
+}
+
+func getBlock(ctx context.Context, client runtimeClient.RuntimeClient, args []string) *block.Block {
+	var id common.Namespace
+	if err := id.UnmarshalHex(args[0]); err != nil {
+		logger.Error("failed to decode runtime id",
+			"err", err,
+		)
+		os.Exit(1)
+	}
+
+	round := viper.GetUint64(cfgInspectRound)
+	blk, err := client.GetBlock(ctx, &runtimeClient.GetBlockRequest{RuntimeID: id, Round: round})
+	if err != nil {
+		logger.Error("failed to get block",
+			"err", err,
+			"round", round,
+		)
+		os.Exit(1)
+	}
+	return blk
+}
+
+func openTree(rs storageAPI.Backend, blk *block.Block) mkvs.Tree {
+	rootType, err := parseRootType(viper.GetString(cfgInspectRootType))
+	if err != nil {
+		logger.Error("failed to parse root type",
+			"err", err,
+		)
+		os.Exit(1)
+	}
+
+	root := node.Root{
+		Namespace: blk.Header.Namespace,
+		Version:   blk.Header.Round,
+		Type:      rootType,
+		Hash:      blk.Header.StateRoot,
+	}
+	if rootType == node.RootTypeIO {
+		root.Hash = blk.Header.IORoot
+	}
+
+	return mkvs.NewWithRoot(rs, nil, root)
+}
+
+func printJSON(v interface{}) {
+	prettyJSON, err := cmdCommon.PrettyJSONMarshal(v)
+	if err != nil {
+		logger.Error("failed to get pretty JSON",
+			"err", err,
+		)
+		os.Exit(1)
+	}
+	fmt.Println(string(prettyJSON))
+}
+
+// ensureDebugStorage exits with an error unless the node exposes the storage service, which is
+// only registered when the node is running with debug options enabled.
+func ensureDebugStorage(ctx context.Context, nodeCtrl control.NodeController) {
+	status, err := nodeCtrl.GetStatus(ctx)
+	if err != nil {
+		logger.Error("failed to get node status",
+			"err", err,
+		)
+		os.Exit(1)
+	}
+	if status.Debug == nil || !status.Debug.Enabled {
+		logger.Error("direct storage access is only available on nodes running with --" + flags.CfgDebugDontBlameOasis)
+		os.Exit(1)
+	}
+}
+
+func doBlock(cmd *cobra.Command, args []string) {
+	ctx := context.Background()
+
+	conn, _ := cmdControl.DoConnect(cmd)
+	client := runtimeClient.NewRuntimeClient(conn)
+	defer conn.Close()
+
+	printJSON(getBlock(ctx, client, args))
+}
+
+func doGet(cmd *cobra.Command, args []string) {
+	ctx := context.Background()
+
+	conn, nodeCtrl := cmdControl.DoConnect(cmd)
+	client := runtimeClient.NewRuntimeClient(conn)
+	storageClient := storageAPI.NewStorageClient(conn)
+	defer conn.Close()
+
+	ensureDebugStorage(ctx, nodeCtrl)
+
+	key, err := hex.DecodeString(args[1])
+	if err != nil {
+		logger.Error("failed to decode key",
+			"err", err,
+		)
+		os.Exit(1)
+	}
+
+	tree := openTree(storageClient, getBlock(ctx, client, args))
+	defer tree.Close()
+
+	value, err := tree.Get(ctx, key)
+	if err != nil {
+		logger.Error("failed to get key",
+			"err", err,
+			"key", args[1],
+		)
+		os.Exit(1)
+	}
+	if value == nil {
+		logger.Error("key not found",
+			"key", args[1],
+		)
+		os.Exit(1)
+	}
+
+	printJSON(&keyValue{Key: args[1], Value: hex.EncodeToString(value)})
+}
+
+func doDump(cmd *cobra.Command, args []string) {
+	ctx := context.Background()
+
+	conn, nodeCtrl := cmdControl.DoConnect(cmd)
+	client := runtimeClient.NewRuntimeClient(conn)
+	storageClient := storageAPI.NewStorageClient(conn)
+	defer conn.Close()
+
+	ensureDebugStorage(ctx, nodeCtrl)
+
+	tree := openTree(storageClient, getBlock(ctx, client, args))
+	defer tree.Close()
+
+	it := tree.NewIterator(ctx)
+	defer it.Close()
+
+	// Entries are printed as they are fetched, one JSON object per line.
+	enc := json.NewEncoder(os.Stdout)
+	for it.Rewind(); it.Valid(); it.Next() {
+		if err := enc.Encode(&keyValue{
+			Key:   hex.EncodeToString(it.Key()),
+			Value: hex.EncodeToString(it.Value()),
+		}); err != nil {
+			logger.Error("failed to write entry",
+				"err", err,
+			)
+			os.Exit(1)
+		}
+	}
+	if err := it.Err(); err != nil {
+		logger.Error("failed to iterate over storage root",
+			"err", err,
+		)
+		os.Exit(1)
+	}
+}
+
+func init() {
+	storageInspectFlags.Uint64(cfgInspectRound, runtimeClient.RoundLatest, "runtime round (defaults to the latest round)")
+	storageInspectFlags.String(cfgInspectRootType, "state", "storage root type (state, io)")
+	_ = viper.BindPFlags(storageInspectFlags)
+}
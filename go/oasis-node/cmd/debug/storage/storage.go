@@ -224,6 +224,17 @@ func Register(parentCmd *cobra.Command) {
 
 	storageBenchmarkCmd.Flags().AddFlagSet(storageBenchmarkFlags)
 
+	for _, cmd := range []*cobra.Command{
+		storageBlockCmd,
+		storageGetCmd,
+		storageDumpCmd,
+	} {
+		cmd.PersistentFlags().AddFlagSet(cmdGrpc.ClientFlags)
+		cmd.PersistentFlags().AddFlagSet(cmdFlags.DebugDontBlameOasisFlag)
+		cmd.Flags().AddFlagSet(storageInspectFlags)
+		storageCmd.AddCommand(cmd)
+	}
+
 	storageCmd.AddCommand(storageCheckRootsCmd)
 	storageCmd.AddCommand(storageExportCmd)
 	storageCmd.AddCommand(storageBenchmarkCmd)
@@ -4,8 +4,11 @@ package node
 import (
 	"context"
 	"fmt"
+	"os"
+	"os/signal"
 	"path/filepath"
 	"sync"
+	"syscall"
 
 	beacon "github.com/oasisprotocol/oasis-core/go/beacon/api"
 	"github.com/oasisprotocol/oasis-core/go/common"
@@ -428,6 +431,7 @@ func NewNode() (node *Node, err error) { // nolint: gocyclo
 		readyCh: make(chan struct{}),
 		logger:  logger,
 	}
+	node.svcMgr.SetStopTimeout(config.GlobalConfig.Common.StopTimeout)
 
 	// Cleanup on error.
 	defer func(node *Node) {
@@ -628,7 +632,33 @@ func NewNode() (node *Node, err error) { // nolint: gocyclo
 		return nil, err
 	}
 
+	// Reload configuration on SIGHUP.
+	go node.watchConfigReload(node.svcMgr.Ctx)
+
 	logger.Info("initialization complete: ready to serve")
 
 	return node, nil
 }
+
+// watchConfigReload reloads the node configuration whenever SIGHUP is received.
+func (n *Node) watchConfigReload(ctx context.Context) {
+	sigCh := make(chan os.Signal, 1)
+	signal.Notify(sigCh, syscall.SIGHUP)
+	defer signal.Stop(sigCh)
+
+	for {
+		select {
+		case <-sigCh:
+		case <-ctx.Done():
+			return
+		}
+
+		n.logger.Info("reloading configuration")
+
+		if err := cmdCommon.ReloadConfig(); err != nil {
+			n.logger.Error("failed to reload configuration",
+				"err", err,
+			)
+		}
+	}
+}
@@ -5,11 +5,14 @@ import (
 	"context"
 	"errors"
 	"fmt"
+	"net"
 	"os"
 	"path/filepath"
 	"time"
 
 	"github.com/spf13/cobra"
+	flag "github.com/spf13/pflag"
+	"github.com/spf13/viper"
 
 	"github.com/oasisprotocol/oasis-core/go/common"
 	"github.com/oasisprotocol/oasis-core/go/common/crypto/hash"
@@ -25,6 +28,12 @@ import (
 	workerStorage "github.com/oasisprotocol/oasis-core/go/worker/storage"
 )
 
+const (
+	cfgHistoryReset = "reset"
+
+	nodeRunningDialTimeout = 1 * time.Second
+)
+
 var (
 	storageCmd = &cobra.Command{
 		Use:   "storage",
@@ -52,6 +61,20 @@ var (
 		RunE:  doRenameNs,
 	}
 
+	storageHistoryCmd = &cobra.Command{
+		Use:   "history",
+		Short: "runtime history utilities",
+	}
+
+	storageHistoryCheckCmd = &cobra.Command{
+		Use:   "check <runtime...>",
+		Args:  cobra.MinimumNArgs(1),
+		Short: "check runtime history for consistency",
+		RunE:  doHistoryCheck,
+	}
+
+	storageHistoryCheckFlags = flag.NewFlagSet("", flag.ContinueOnError)
+
 	logger = logging.GetLogger("cmd/storage")
 
 	pretty = cmdCommon.Isatty(1)
@@ -281,6 +304,111 @@ func doRenameNs(_ *cobra.Command, args []string) error {
 	return nil
 }
 
+// checkHistory verifies that the stored runtime blocks form a contiguous hash chain.
+func checkHistory(ctx context.Context, h history.History, display *displayHelper) error {
+	earliest, err := h.GetEarliestBlock(ctx)
+	if err != nil {
+		if errors.Is(err, roothash.ErrNotFound) {
+			display.Display("history is empty")
+			return nil
+		}
+		return fmt.Errorf("failed to get earliest block: %w", err)
+	}
+	latest, err := h.GetBlock(ctx, roothash.RoundLatest)
+	if err != nil {
+		return fmt.Errorf("failed to get latest block: %w", err)
+	}
+
+	display.DisplayStepBegin("verifying block headers")
+	first, last := earliest.Header.Round, latest.Header.Round
+	prevHash := earliest.Header.EncodedHash()
+	for round := first + 1; round <= last; round++ {
+		display.DisplayProgress("rounds", round-first, last-first)
+
+		blk, err := h.GetBlock(ctx, round)
+		if err != nil {
+			return fmt.Errorf("failed to get block for round %d: %w", round, err)
+		}
+		if blk.Header.Round != round {
+			return fmt.Errorf("unexpected block round (expected: %d got: %d)", round, blk.Header.Round)
+		}
+		if !blk.Header.PreviousHash.Equal(&prevHash) {
+			return fmt.Errorf("block for round %d does not extend the previous block", round)
+		}
+		prevHash = blk.Header.EncodedHash()
+	}
+	display.DisplayStepEnd(fmt.Sprintf("rounds %d to %d ok", first, last))
+
+	return nil
+}
+
+// ensureNodeStopped returns an error if a node is using the data directory.
+func ensureNodeStopped() error {
+	socketPath := cmdCommon.InternalSocketPath()
+	conn, err := net.DialTimeout("unix", socketPath, nodeRunningDialTimeout)
+	if err != nil {
+		return nil
+	}
+	conn.Close()
+	return fmt.Errorf("node is running (internal socket %s accepts connections), stop it first", socketPath)
+}
+
+func doHistoryCheck(_ *cobra.Command, args []string) error {
+	dataDir := cmdCommon.DataDir()
+	ctx := context.Background()
+
+	runtimes, err := parseRuntimes(args)
+	cobra.CheckErr(err)
+
+	if err = ensureNodeStopped(); err != nil {
+		return err
+	}
+
+	for _, rt := range runtimes {
+		if pretty {
+			fmt.Printf("Checking history for runtime %v...\n", rt)
+		}
+		err := func() error {
+			runtimeDir := registry.GetRuntimeStateDir(dataDir, rt)
+			display := &displayHelper{}
+
+			// Opening the history fails if its database is locked by a running node.
+			h, err := history.New(runtimeDir, rt, nil, false)
+			if err != nil {
+				return fmt.Errorf("error opening history (is the node running?): %w", err)
+			}
+			checkErr := checkHistory(ctx, h, display)
+			h.Close()
+
+			switch {
+			case checkErr == nil:
+				display.Display("history is consistent")
+				return nil
+			case !viper.GetBool(cfgHistoryReset):
+				return fmt.Errorf("history is inconsistent: %w", checkErr)
+			}
+
+			// The node indexes the history from consensus on the next start, beginning at the
+			// last retained consensus height.
+			display.Display(fmt.Sprintf("history is inconsistent: %v", checkErr))
+			if err = os.RemoveAll(filepath.Join(runtimeDir, history.DbFilename)); err != nil {
+				return fmt.Errorf("failed to remove history database: %w", err)
+			}
+			display.Display("history removed, it will be reindexed on the next start")
+			logger.Info("runtime history reset", "rt", rt)
+			return nil
+		}()
+		if err != nil {
+			logger.Error("error checking runtime history", "rt", rt, "err", err)
+			if pretty {
+				fmt.Printf("error checking history for runtime %v: %v\n", rt, err)
+			}
+			return fmt.Errorf("error checking history for runtime %v: %w", rt, err)
+		}
+	}
+	return nil
+}
+
 // Register registers the client sub-command and all of its children.
 func Register(parentCmd *cobra.Command) {
 	storageMigrateCmd.Flags().AddFlagSet(registry.Flags)
@@ -288,5 +416,13 @@ func Register(parentCmd *cobra.Command) {
 	storageCmd.AddCommand(storageMigrateCmd)
 	storageCmd.AddCommand(storageCheckCmd)
 	storageCmd.AddCommand(storageRenameNsCmd)
+	storageHistoryCheckCmd.Flags().AddFlagSet(storageHistoryCheckFlags)
+	storageHistoryCmd.AddCommand(storageHistoryCheckCmd)
+	storageCmd.AddCommand(storageHistoryCmd)
 	parentCmd.AddCommand(storageCmd)
 }
+
+func init() {
+	storageHistoryCheckFlags.Bool(cfgHistoryReset, false, "remove inconsistent history so it is reindexed from consensus")
+	_ = viper.BindPFlags(storageHistoryCheckFlags)
+}
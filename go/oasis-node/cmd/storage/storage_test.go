@@ -0,0 +1,64 @@
+package storage
+
+import (
+	"context"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+
+	"github.com/oasisprotocol/oasis-core/go/common"
+	roothash "github.com/oasisprotocol/oasis-core/go/roothash/api"
+	"github.com/oasisprotocol/oasis-core/go/roothash/api/block"
+	"github.com/oasisprotocol/oasis-core/go/runtime/history"
+)
+
+func commitBlocks(t *testing.T, h history.History, blks []*block.Block) {
+	for i, blk := range blks {
+		err := h.Commit(&roothash.AnnotatedBlock{Height: int64(i + 1), Block: blk}, &roothash.RoundResults{}, false)
+		require.NoError(t, err, "Commit")
+	}
+}
+
+func TestCheckHistory(t *testing.T) {
+	require := require.New(t)
+	ctx := context.Background()
+	runtimeID := common.NewTestNamespaceFromSeed([]byte("storage cmd test ns"), 0)
+
+	genesis := block.NewGenesisBlock(runtimeID, 0)
+	blk1 := block.NewEmptyBlock(genesis, 1, block.Normal)
+	blk2 := block.NewEmptyBlock(blk1, 2, block.Normal)
+
+	// Empty history.
+	h, err := history.New(t.TempDir(), runtimeID, nil, false)
+	require.NoError(err, "history.New")
+	defer h.Close()
+	err = checkHistory(ctx, h, &displayHelper{})
+	require.NoError(err, "checkHistory should succeed for empty history")
+
+	// Consistent history.
+	commitBlocks(t, h, []*block.Block{genesis, blk1, blk2})
+	err = checkHistory(ctx, h, &displayHelper{})
+	require.NoError(err, "checkHistory should succeed for consistent history")
+
+	// History that does not form a hash chain.
+	broken := block.NewEmptyBlock(blk1, 3, block.Normal)
+	broken.Header.Round = blk2.Header.Round + 1
+
+	h2, err := history.New(t.TempDir(), runtimeID, nil, false)
+	require.NoError(err, "history.New")
+	defer h2.Close()
+	commitBlocks(t, h2, []*block.Block{genesis, blk1, blk2, broken})
+	err = checkHistory(ctx, h2, &displayHelper{})
+	require.Error(err, "checkHistory should fail for broken hash chain")
+
+	// History with a missing round.
+	gap := block.NewEmptyBlock(blk2, 4, block.Normal)
+	gap.Header.Round = blk2.Header.Round + 2
+
+	h3, err := history.New(t.TempDir(), runtimeID, nil, false)
+	require.NoError(err, "history.New")
+	defer h3.Close()
+	commitBlocks(t, h3, []*block.Block{genesis, blk1, blk2, gap})
+	err = checkHistory(ctx, h3, &displayHelper{})
+	require.Error(err, "checkHistory should fail for missing rounds")
+}
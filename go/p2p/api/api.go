@@ -125,6 +125,10 @@ type PeerRegistry interface {
 
 	// NumPeers returns the number of registered peers.
 	NumPeers() int
+
+	// IsRegistered returns true iff the given peer belongs to an active, non-frozen node
+	// registration.
+	IsRegistered(peerID core.PeerID) bool
 }
 
 // PeerTagger is an interface for tagging important peers.
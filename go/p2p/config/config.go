@@ -14,12 +14,18 @@ type Config struct {
 	// Seed node(s) of the form pubkey@IP:port.
 	Seeds []string `yaml:"seeds,omitempty"`
 
+	// Only accept protocol streams from persistent peers and peers of active, non-frozen
+	// registered nodes. Nodes that serve unregistered clients (e.g., state sync) should not
+	// enable this.
+	RegisteredPeersOnly bool `yaml:"registered_peers_only,omitempty"`
+
 	Discovery         DiscoveryConfig         `yaml:"discovery,omitempty"`
 	Registration      RegistrationConfig      `yaml:"registration,omitempty"`
 	Gossipsub         GossipsubConfig         `yaml:"gossipsub,omitempty"`
 	PeerManager       PeerManagerConfig       `yaml:"peer_manager,omitempty"`
 	ConnectionManager ConnectionManagerConfig `yaml:"connection_manager,omitempty"`
 	ConnectionGater   ConnectionGaterConfig   `yaml:"connection_gater,omitempty"`
+	RPC               RPCConfig               `yaml:"rpc,omitempty"`
 }
 
 // DiscoveryConfig is the P2P discovery configuration structure.
@@ -77,6 +83,17 @@ type ConnectionGaterConfig struct {
 	BlockedPeerIPs []string `yaml:"blocked_peers"`
 }
 
+// RPCConfig is the P2P RPC server configuration structure.
+type RPCConfig struct {
+	// Maximum number of requests per second a single peer may make to a protocol server
+	// (0 disables rate limiting).
+	RateLimit float64 `yaml:"rate_limit"`
+	// Maximum number of requests a single peer may make to a protocol server in a burst.
+	RateLimitBurst int `yaml:"rate_limit_burst"`
+	// Maximum amount of time that can be spent on handling a single request.
+	RequestHandleTimeout time.Duration `yaml:"request_handle_timeout"`
+}
+
 // Validate validates the configuration settings.
 func (c *Config) Validate() error {
 	if c.ConnectionManager.MaxNumPeers < 0 {
@@ -96,6 +113,16 @@ func (c *Config) Validate() error {
 		return fmt.Errorf("gossipsub.validate_throttle must be >= 0")
 	}
 
+	if c.RPC.RateLimit < 0 {
+		return fmt.Errorf("rpc.rate_limit must be >= 0")
+	}
+	if c.RPC.RateLimit > 0 && c.RPC.RateLimitBurst < 1 {
+		return fmt.Errorf("rpc.rate_limit_burst must be >= 1 when rate limiting is enabled")
+	}
+	if c.RPC.RequestHandleTimeout <= 0 {
+		return fmt.Errorf("rpc.request_handle_timeout must be > 0")
+	}
+
 	return nil
 }
 
@@ -130,5 +157,10 @@ func DefaultConfig() Config {
 		ConnectionGater: ConnectionGaterConfig{
 			BlockedPeerIPs: []string{},
 		},
+		RPC: RPCConfig{
+			RateLimit:            100,
+			RateLimitBurst:       200,
+			RequestHandleTimeout: 60 * time.Second,
+		},
 	}
 }
@@ -94,5 +94,5 @@ func (s *service) handleDiscovery(req *DiscoverRequest) (*DiscoverResponse, erro
 
 // NewServer creates a new bootstrap protocol server.
 func NewServer(s *peerstore.Store) rpc.Server {
-	return rpc.NewServer(ProtocolID(), newSeedService(s))
+	return rpc.NewServer(ProtocolID(), newSeedService(s), rpc.ServerOptionsFromConfig()...)
 }
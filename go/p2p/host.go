@@ -137,16 +137,30 @@ func (cfg *ConnManagerConfig) NewConnManager() (*connmgr.BasicConnMgr, error) {
 
 // Load loads connection manager configuration.
 func (cfg *ConnManagerConfig) Load() error {
+	persistentPeers, err := parsePersistentPeers(config.GlobalConfig.P2P.ConnectionManager.PersistentPeers)
+	if err != nil {
+		return err
+	}
+
+	cfg.MinPeers = config.GlobalConfig.P2P.ConnectionManager.MaxNumPeers
+	cfg.MaxPeers = cfg.MinPeers + peersHighWatermarkDelta
+	cfg.GracePeriod = config.GlobalConfig.P2P.ConnectionManager.PeerGracePeriod
+	cfg.PersistentPeers = persistentPeers
+
+	return nil
+}
+
+func parsePersistentPeers(addrs []string) ([]peer.ID, error) {
 	persistentPeersMap := make(map[core.PeerID]struct{})
-	for _, pp := range config.GlobalConfig.P2P.ConnectionManager.PersistentPeers {
+	for _, pp := range addrs {
 		var addr node.ConsensusAddress
 		if err := addr.UnmarshalText([]byte(pp)); err != nil {
-			return fmt.Errorf("malformed address (expected pubkey@IP:port): %w", err)
+			return nil, fmt.Errorf("malformed address (expected pubkey@IP:port): %w", err)
 		}
 
 		pid, err := api.PublicKeyToPeerID(addr.ID)
 		if err != nil {
-			return fmt.Errorf("invalid public key (%s): %w", addr.ID, err)
+			return nil, fmt.Errorf("invalid public key (%s): %w", addr.ID, err)
 		}
 
 		persistentPeersMap[pid] = struct{}{}
@@ -157,12 +171,7 @@ func (cfg *ConnManagerConfig) Load() error {
 		persistentPeers = append(persistentPeers, pid)
 	}
 
-	cfg.MinPeers = config.GlobalConfig.P2P.ConnectionManager.MaxNumPeers
-	cfg.MaxPeers = cfg.MinPeers + peersHighWatermarkDelta
-	cfg.GracePeriod = config.GlobalConfig.P2P.ConnectionManager.PeerGracePeriod
-	cfg.PersistentPeers = persistentPeers
-
-	return nil
+	return persistentPeers, nil
 }
 
 // ConnGaterConfig describes a set of settings for a connection gater.
@@ -193,18 +202,27 @@ func (cfg *ConnGaterConfig) NewConnGater() (*conngater.BasicConnectionGater, err
 
 // Load loads connection gater configuration.
 func (cfg *ConnGaterConfig) Load() error {
+	blockedPeers, err := parseBlockedPeerIPs(config.GlobalConfig.P2P.ConnectionGater.BlockedPeerIPs)
+	if err != nil {
+		return err
+	}
+
+	cfg.BlockedPeers = blockedPeers
+
+	return nil
+}
+
+func parseBlockedPeerIPs(ips []string) ([]net.IP, error) {
 	blockedPeers := make([]net.IP, 0)
-	for _, blockedIP := range config.GlobalConfig.P2P.ConnectionGater.BlockedPeerIPs {
+	for _, blockedIP := range ips {
 		parsedIP := net.ParseIP(blockedIP)
 		if parsedIP == nil {
-			return fmt.Errorf("malformed blocked IP: %s", blockedIP)
+			return nil, fmt.Errorf("malformed blocked IP: %s", blockedIP)
 		}
 		blockedPeers = append(blockedPeers, parsedIP)
 	}
 
-	cfg.BlockedPeers = blockedPeers
-
-	return nil
+	return blockedPeers, nil
 }
 
 // NewResourceManager constructs a new resource manager.
@@ -82,7 +82,8 @@ func (p *nopP2P) RegisterProtocol(core.ProtocolID, int, int) {
 }
 
 // Implements api.Service.
-func (p *nopP2P) RegisterProtocolServer(rpc.Server) {
+func (p *nopP2P) RegisterProtocolServer(srv rpc.Server) {
+	srv.Close()
 }
 
 // Implements api.Service.
@@ -12,6 +12,7 @@ import (
 	pb "github.com/libp2p/go-libp2p-pubsub/pb"
 	"github.com/libp2p/go-libp2p/core"
 	"github.com/libp2p/go-libp2p/core/discovery"
+	"github.com/libp2p/go-libp2p/core/network"
 	"github.com/libp2p/go-libp2p/core/peer"
 	"github.com/libp2p/go-libp2p/p2p/net/conngater"
 	"github.com/multiformats/go-multiaddr"
@@ -81,8 +82,13 @@ type p2p struct {
 	gater   *conngater.BasicConnectionGater
 	peerMgr *peermgmt.PeerManager
 
-	registerAddresses []multiaddr.Multiaddr
-	topics            map[string]*topicHandler
+	registerAddresses   []multiaddr.Multiaddr
+	registeredPeersOnly bool
+	topics              map[string]*topicHandler
+	servers             []rpc.Server
+
+	persistentPeers []core.PeerID
+	blockedPeers    []net.IP
 
 	logger *logging.Logger
 }
@@ -102,6 +108,7 @@ func (p *p2p) Start() error {
 	// However, we can start everything else.
 	p.peerMgr.Start()
 	go p.metricsWorker()
+	go p.configWorker()
 
 	return nil
 }
@@ -112,6 +119,12 @@ func (p *p2p) Stop() {
 
 	p.ctxCancel()
 
+	p.RLock()
+	for _, srv := range p.servers {
+		srv.Close()
+	}
+	p.RUnlock()
+
 	var wg sync.WaitGroup
 	defer wg.Wait()
 	wg.Add(3)
@@ -339,13 +352,39 @@ func (p *p2p) PeerManager() api.PeerManager {
 func (p *p2p) RegisterProtocolServer(srv rpc.Server) {
 	protocol.ValidateProtocolID(srv.Protocol())
 
-	p.host.SetStreamHandler(srv.Protocol(), srv.HandleStream)
+	handler := srv.HandleStream
+	if p.registeredPeersOnly {
+		handler = p.authenticateStream(handler)
+	}
+	p.host.SetStreamHandler(srv.Protocol(), handler)
+
+	p.Lock()
+	p.servers = append(p.servers, srv)
+	p.Unlock()
 
 	p.logger.Info("registered protocol server",
 		"protocol_id", srv.Protocol(),
+		"registered_peers_only", p.registeredPeersOnly,
 	)
 }
 
+// authenticateStream wraps the given stream handler so that it only accepts streams from
+// persistent peers and peers of active, non-frozen registered nodes.
+func (p *p2p) authenticateStream(handler network.StreamHandler) network.StreamHandler {
+	return func(s network.Stream) {
+		peerID := s.Conn().RemotePeer()
+		if !p.host.ConnManager().IsProtected(peerID, "") && !p.peerMgr.PeerRegistry().IsRegistered(peerID) {
+			p.logger.Debug("rejecting stream from unregistered peer",
+				"peer_id", peerID,
+				"protocol_id", s.Protocol(),
+			)
+			_ = s.Reset()
+			return
+		}
+		handler(s)
+	}
+}
+
 // Implements api.Service.
 func (p *p2p) GetMinRepublishInterval() time.Duration {
 	return seenMessagesTTL + 5*time.Second
@@ -419,19 +458,22 @@ func New(identity *identity.Identity, consensus consensus.Backend, store *persis
 	mgr := peermgmt.NewPeerManager(host, cg, pubsub, consensus, chainContext, store, opts...)
 
 	p := &p2p{
-		ctx:               ctx,
-		ctxCancel:         ctxCancel,
-		quitCh:            make(chan struct{}),
-		metricsClosedCh:   make(chan struct{}),
-		chainContext:      chainContext,
-		signer:            identity.P2PSigner,
-		host:              host,
-		gater:             cg,
-		peerMgr:           mgr,
-		pubsub:            pubsub,
-		registerAddresses: cfg.Addresses,
-		topics:            make(map[string]*topicHandler),
-		logger:            logging.GetLogger("p2p"),
+		ctx:                 ctx,
+		ctxCancel:           ctxCancel,
+		quitCh:              make(chan struct{}),
+		metricsClosedCh:     make(chan struct{}),
+		chainContext:        chainContext,
+		signer:              identity.P2PSigner,
+		host:                host,
+		gater:               cg,
+		peerMgr:             mgr,
+		pubsub:              pubsub,
+		registerAddresses:   cfg.Addresses,
+		registeredPeersOnly: cfg.RegisteredPeersOnly,
+		topics:              make(map[string]*topicHandler),
+		persistentPeers:     cfg.ConnManagerConfig.PersistentPeers,
+		blockedPeers:        cfg.BlockedPeers,
+		logger:              logging.GetLogger("p2p"),
 	}
 
 	p.logger.Info("p2p host initialized",
@@ -449,7 +491,8 @@ func New(identity *identity.Identity, consensus consensus.Backend, store *persis
 
 // Config describes a set of P2P settings for a peer.
 type Config struct {
-	Addresses []multiaddr.Multiaddr
+	Addresses           []multiaddr.Multiaddr
+	RegisteredPeersOnly bool
 
 	HostConfig
 	GossipSubConfig
@@ -488,6 +531,7 @@ func (cfg *Config) Load() error {
 	}
 
 	cfg.Addresses = addresses
+	cfg.RegisteredPeersOnly = config.GlobalConfig.P2P.RegisteredPeersOnly
 	cfg.HostConfig = hostCfg
 	cfg.GossipSubConfig = gossipSubCfg
 	cfg.BootstrapDiscoveryConfig = bootstrapCfg
@@ -100,7 +100,7 @@ func NewPeerManager(
 		logger:    l,
 		host:      h,
 		pubsub:    ps,
-		registry:  newPeerRegistry(consensus, chainContext),
+		registry:  newPeerRegistry(h, consensus, chainContext),
 		connector: newPeerConnector(h, g),
 		tagger:    newPeerTagger(cm),
 		backup:    newPeerstoreBackup(h.Peerstore(), cstore),
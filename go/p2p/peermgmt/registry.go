@@ -6,26 +6,39 @@ import (
 	"sync"
 
 	"github.com/libp2p/go-libp2p/core"
+	"github.com/libp2p/go-libp2p/core/host"
 	"github.com/libp2p/go-libp2p/core/peer"
 	manet "github.com/multiformats/go-multiaddr/net"
 
+	"github.com/oasisprotocol/oasis-core/go/common/crypto/signature"
 	"github.com/oasisprotocol/oasis-core/go/common/logging"
 	"github.com/oasisprotocol/oasis-core/go/common/node"
 	cmSync "github.com/oasisprotocol/oasis-core/go/common/sync"
 	consensus "github.com/oasisprotocol/oasis-core/go/consensus/api"
 	"github.com/oasisprotocol/oasis-core/go/p2p/api"
+	registry "github.com/oasisprotocol/oasis-core/go/registry/api"
+	staking "github.com/oasisprotocol/oasis-core/go/staking/api"
 )
 
+// maxConcurrentStatusQueries is the maximum number of node status queries in flight at once.
+const maxConcurrentStatusQueries = 16
+
 type peerRegistry struct {
 	logger *logging.Logger
 
+	host         host.Host
 	consensus    consensus.Backend
 	chainContext string
 
+	// getNodeStatus queries the registry status of the given node.
+	getNodeStatus func(context.Context, signature.PublicKey) (*registry.NodeStatus, error)
+
 	mu            sync.Mutex
 	peers         map[core.PeerID]peer.AddrInfo
 	protocolPeers map[core.ProtocolID]map[core.PeerID]struct{}
 	topicPeers    map[string]map[core.PeerID]struct{}
+	nodes         map[signature.PublicKey]*node.Node
+	frozenPeers   map[core.PeerID]struct{}
 
 	initCh   chan struct{}
 	initOnce sync.Once
@@ -33,19 +46,32 @@ type peerRegistry struct {
 	startOne cmSync.One
 }
 
-func newPeerRegistry(c consensus.Backend, chainContext string) *peerRegistry {
+func newPeerRegistry(h host.Host, c consensus.Backend, chainContext string) *peerRegistry {
 	l := logging.GetLogger("p2p/peer-manager/registry")
 
-	return &peerRegistry{
+	r := &peerRegistry{
 		logger:        l,
+		host:          h,
 		consensus:     c,
 		chainContext:  chainContext,
 		peers:         make(map[core.PeerID]peer.AddrInfo),
 		protocolPeers: make(map[core.ProtocolID]map[core.PeerID]struct{}),
 		topicPeers:    make(map[string]map[core.PeerID]struct{}),
+		nodes:         make(map[signature.PublicKey]*node.Node),
+		frozenPeers:   make(map[core.PeerID]struct{}),
 		initCh:        make(chan struct{}),
 		startOne:      cmSync.NewOne(),
 	}
+	if c != nil {
+		r.getNodeStatus = func(ctx context.Context, id signature.PublicKey) (*registry.NodeStatus, error) {
+			return c.Registry().GetNodeStatus(ctx, &registry.IDQuery{
+				Height: consensus.HeightLatest,
+				ID:     id,
+			})
+		}
+	}
+
+	return r
 }
 
 // Implements api.PeerRegistry.
@@ -61,6 +87,18 @@ func (r *peerRegistry) NumPeers() int {
 	return len(r.peers)
 }
 
+// Implements api.PeerRegistry.
+func (r *peerRegistry) IsRegistered(p core.PeerID) bool {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	if _, ok := r.peers[p]; !ok {
+		return false
+	}
+	_, frozen := r.frozenPeers[p]
+	return !frozen
+}
+
 func (r *peerRegistry) findProtocolPeers(ctx context.Context, p core.ProtocolID) <-chan peer.AddrInfo {
 	getPeerMap := func() map[peer.ID]struct{} {
 		return r.protocolPeers[p]
@@ -155,14 +193,48 @@ func (r *peerRegistry) watch(ctx context.Context) {
 	}
 	defer nSub.Close()
 
+	// Listen to registry events for nodes being unfrozen.
+	regEvCh, regEvSub, err := r.consensus.Registry().WatchEvents(ctx)
+	if err != nil {
+		r.logger.Error("failed to watch registry events",
+			"err", err,
+		)
+		return
+	}
+	defer regEvSub.Close()
+
+	// Listen to staking events for nodes being frozen when their entity is slashed.
+	stakingEvCh, stakingEvSub, err := r.consensus.Staking().WatchEvents(ctx)
+	if err != nil {
+		r.logger.Error("failed to watch staking events",
+			"err", err,
+		)
+		return
+	}
+	defer stakingEvSub.Close()
+
 	for {
 		select {
 		case nodes := <-nodeListCh:
 			r.handleNodes(nodes.Nodes, true)
+			r.refreshFrozenNodes(ctx, nodes.Nodes)
 
 		case nodeEv := <-nodeCh:
 			if nodeEv.IsRegistration {
 				r.handleNodes([]*node.Node{nodeEv.Node}, false)
+				r.refreshFrozenNodes(ctx, []*node.Node{nodeEv.Node})
+				continue
+			}
+			r.removeNodes([]*node.Node{nodeEv.Node})
+
+		case ev := <-regEvCh:
+			if ev.NodeUnfrozenEvent != nil {
+				r.unfreezeNode(ev.NodeUnfrozenEvent.NodeID)
+			}
+
+		case ev := <-stakingEvCh:
+			if ev.Escrow != nil && ev.Escrow.Take != nil {
+				r.refreshFrozenNodes(ctx, r.entityNodes(ev.Escrow.Take.Owner))
 			}
 
 		case <-ctx.Done():
@@ -207,6 +279,10 @@ func (r *peerRegistry) handleNodes(nodes []*node.Node, reset bool) {
 		r.peers = make(map[core.PeerID]peer.AddrInfo)
 		r.protocolPeers = make(map[core.ProtocolID]map[core.PeerID]struct{})
 		r.topicPeers = make(map[string]map[core.PeerID]struct{})
+		r.nodes = make(map[signature.PublicKey]*node.Node)
+	}
+	for _, n := range nodes {
+		r.nodes[n.ID] = n
 	}
 
 	// Add/update new peers.
@@ -240,6 +316,151 @@ func (r *peerRegistry) handleNodes(nodes []*node.Node, reset bool) {
 		// Update the address, as it might have changed.
 		r.peers[p] = data.info
 	}
+
+	// Forget frozen peers that are no longer registered.
+	for p := range r.frozenPeers {
+		if _, ok := r.peers[p]; !ok {
+			delete(r.frozenPeers, p)
+		}
+	}
+}
+
+// refreshFrozenNodes queries the registry status of the given nodes concurrently and updates
+// which of them are frozen. Nodes whose status cannot be queried keep their previous state.
+func (r *peerRegistry) refreshFrozenNodes(ctx context.Context, nodes []*node.Node) {
+	if r.getNodeStatus == nil || len(nodes) == 0 {
+		return
+	}
+
+	var (
+		wg     sync.WaitGroup
+		mu     sync.Mutex
+		frozen = make(map[core.PeerID]bool)
+		sem    = make(chan struct{}, maxConcurrentStatusQueries)
+	)
+	for _, n := range nodes {
+		info, err := p2pInfoToAddrInfo(&n.P2P) //nolint:gosec
+		if err != nil {
+			// Conversion errors are reported when handling nodes.
+			continue
+		}
+
+		sem <- struct{}{}
+		wg.Add(1)
+		go func(id signature.PublicKey, p core.PeerID) {
+			defer func() {
+				<-sem
+				wg.Done()
+			}()
+
+			status, err := r.getNodeStatus(ctx, id)
+			if err != nil {
+				r.logger.Warn("failed to query node status",
+					"err", err,
+					"node_id", id,
+				)
+				return
+			}
+
+			mu.Lock()
+			frozen[p] = status.IsFrozen()
+			mu.Unlock()
+		}(n.ID, info.ID)
+	}
+	wg.Wait()
+
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	for p, isFrozen := range frozen {
+		if _, ok := r.peers[p]; !ok {
+			// Peer has been removed in the meantime.
+			continue
+		}
+		if isFrozen {
+			r.frozenPeers[p] = struct{}{}
+			continue
+		}
+		delete(r.frozenPeers, p)
+	}
+}
+
+// unfreezeNode marks the given node as no longer frozen.
+func (r *peerRegistry) unfreezeNode(id signature.PublicKey) {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	n, ok := r.nodes[id]
+	if !ok {
+		return
+	}
+	p, err := api.PublicKeyToPeerID(n.P2P.ID)
+	if err != nil {
+		return
+	}
+	delete(r.frozenPeers, p)
+}
+
+// entityNodes returns the registered nodes of the entity with the given staking address.
+func (r *peerRegistry) entityNodes(addr staking.Address) []*node.Node {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	var nodes []*node.Node
+	for _, n := range r.nodes {
+		if staking.NewAddress(n.EntityID).Equal(addr) {
+			nodes = append(nodes, n)
+		}
+	}
+	return nodes
+}
+
+// removeNodes removes the given nodes from all protocols and topics, e.g., when they deregister,
+// and closes any connections to them unless they are protected.
+func (r *peerRegistry) removeNodes(nodes []*node.Node) {
+	peers := make([]core.PeerID, 0, len(nodes))
+	for _, n := range nodes {
+		info, err := p2pInfoToAddrInfo(&n.P2P) //nolint:gosec
+		if err != nil {
+			r.logger.Error("failed to convert node to node info",
+				"err", err,
+				"node_id", n.ID,
+			)
+			continue
+		}
+		peers = append(peers, info.ID)
+	}
+
+	r.mu.Lock()
+	for _, p := range peers {
+		for _, pm := range r.protocolPeers {
+			delete(pm, p)
+		}
+		for _, pm := range r.topicPeers {
+			delete(pm, p)
+		}
+		delete(r.peers, p)
+		delete(r.frozenPeers, p)
+	}
+	for _, n := range nodes {
+		delete(r.nodes, n.ID)
+	}
+	r.mu.Unlock()
+
+	if r.host == nil {
+		return
+	}
+	for _, p := range peers {
+		if r.host.ConnManager().IsProtected(p, "") {
+			continue
+		}
+		if err := r.host.Network().ClosePeer(p); err != nil {
+			r.logger.Warn("failed to close connections to removed peer",
+				"err", err,
+				"peer_id", p,
+			)
+		}
+	}
 }
 
 func (r *peerRegistry) inspectNode(n *node.Node) (map[core.ProtocolID]struct{}, map[string]struct{}) {
@@ -0,0 +1,160 @@
+package peermgmt
+
+import (
+	"context"
+	"fmt"
+	"testing"
+	"time"
+
+	"github.com/libp2p/go-libp2p/core"
+	"github.com/libp2p/go-libp2p/core/network"
+	"github.com/libp2p/go-libp2p/core/peer"
+	"github.com/stretchr/testify/require"
+
+	"github.com/oasisprotocol/oasis-core/go/common/crypto/signature"
+	memorySigner "github.com/oasisprotocol/oasis-core/go/common/crypto/signature/signers/memory"
+	"github.com/oasisprotocol/oasis-core/go/common/node"
+	"github.com/oasisprotocol/oasis-core/go/p2p/api"
+	registry "github.com/oasisprotocol/oasis-core/go/registry/api"
+	staking "github.com/oasisprotocol/oasis-core/go/staking/api"
+)
+
+// registerTestNodeHandler registers the given node handler for the duration of the test.
+func registerTestNodeHandler(t *testing.T, h NodeHandler) {
+	nodeHandlers.RLock()
+	prev := nodeHandlers.l
+	nodeHandlers.RUnlock()
+
+	RegisterNodeHandler(h)
+	t.Cleanup(func() {
+		nodeHandlers.Lock()
+		defer nodeHandlers.Unlock()
+		nodeHandlers.l = prev
+	})
+}
+
+func TestPeerRegistryRemoveNodes(t *testing.T) {
+	require := require.New(t)
+
+	protocol := core.ProtocolID("/oasis/test-registry/1.0.0")
+	topic := "oasis/test-registry"
+	registerTestNodeHandler(t, &NodeHandlerBundle{
+		ProtocolsFn: func(*node.Node, string) []core.ProtocolID { return []core.ProtocolID{protocol} },
+		TopicsFn:    func(*node.Node, string) []string { return []string{topic} },
+	})
+
+	h, err := newTestHost()
+	require.NoError(err, "newTestHost failed")
+	defer h.Close()
+
+	// Two registered peers, the second of which is protected.
+	peers := make([]core.PeerID, 2)
+	nodes := make([]*node.Node, 2)
+	for i := range nodes {
+		p, err := newTestHost()
+		require.NoError(err, "newTestHost failed")
+		defer p.Close()
+
+		pk, err := api.PubKeyToPublicKey(p.Peerstore().PubKey(p.ID()))
+		require.NoError(err, "PubKeyToPublicKey failed")
+
+		ctx, cancel := context.WithTimeout(context.Background(), time.Second)
+		defer cancel()
+		err = h.Connect(ctx, peer.AddrInfo{ID: p.ID(), Addrs: p.Addrs()})
+		require.NoError(err, "Connect failed")
+
+		peers[i] = p.ID()
+		nodes[i] = &node.Node{
+			ID:  pk,
+			P2P: node.P2PInfo{ID: pk},
+		}
+	}
+	h.ConnManager().Protect(peers[1], "")
+
+	r := newPeerRegistry(h, nil, "")
+	r.handleNodes(nodes, true)
+	require.Equal(2, r.NumPeers())
+	require.Len(r.protocolPeers[protocol], 2)
+	require.Len(r.topicPeers[topic], 2)
+	require.True(r.IsRegistered(peers[0]), "registered peer should be authenticated")
+	require.False(r.IsRegistered(h.ID()), "unregistered peer should not be authenticated")
+
+	r.frozenPeers[peers[0]] = struct{}{}
+	require.False(r.IsRegistered(peers[0]), "frozen peer should not be authenticated")
+
+	r.removeNodes(nodes)
+	require.Equal(0, r.NumPeers(), "removed peers should be dropped")
+	require.False(r.IsRegistered(peers[1]), "removed peer should not be authenticated")
+	require.Empty(r.frozenPeers, "removed peers should not be frozen")
+	require.Empty(r.protocolPeers[protocol], "removed peers should not support protocols")
+	require.Empty(r.topicPeers[topic], "removed peers should not support topics")
+	require.Equal(network.NotConnected, h.Network().Connectedness(peers[0]), "removed peer should be disconnected")
+	require.Equal(network.Connected, h.Network().Connectedness(peers[1]), "protected peer should stay connected")
+}
+
+func TestPeerRegistryFrozenNodes(t *testing.T) {
+	require := require.New(t)
+
+	entity := memorySigner.NewTestSigner("p2p/peermgmt: test entity").Public()
+	peers := make([]core.PeerID, 2)
+	nodes := make([]*node.Node, 2)
+	for i := range nodes {
+		pk := memorySigner.NewTestSigner(fmt.Sprintf("p2p/peermgmt: test node %d", i)).Public()
+		p, err := api.PublicKeyToPeerID(pk)
+		require.NoError(err, "PublicKeyToPeerID failed")
+
+		peers[i] = p
+		nodes[i] = &node.Node{
+			ID:       pk,
+			EntityID: entity,
+			P2P:      node.P2PInfo{ID: pk},
+		}
+	}
+
+	var (
+		frozen  = make(map[signature.PublicKey]bool)
+		failing = make(map[signature.PublicKey]bool)
+	)
+	r := newPeerRegistry(nil, nil, "")
+	r.getNodeStatus = func(_ context.Context, id signature.PublicKey) (*registry.NodeStatus, error) {
+		if failing[id] {
+			return nil, fmt.Errorf("status unavailable")
+		}
+		var status registry.NodeStatus
+		if frozen[id] {
+			status.FreezeEndTime = registry.FreezeForever
+		}
+		return &status, nil
+	}
+
+	ctx := context.Background()
+	r.handleNodes(nodes, true)
+	r.refreshFrozenNodes(ctx, nodes)
+	require.True(r.IsRegistered(peers[0]))
+	require.True(r.IsRegistered(peers[1]))
+
+	// Query errors should not freeze nodes.
+	failing[nodes[0].ID] = true
+	r.refreshFrozenNodes(ctx, nodes)
+	require.True(r.IsRegistered(peers[0]), "query errors should not freeze nodes")
+
+	// Slashed entities should have their nodes refreshed.
+	frozen[nodes[1].ID] = true
+	entityNodes := r.entityNodes(staking.NewAddress(entity))
+	require.Len(entityNodes, 2)
+	r.refreshFrozenNodes(ctx, entityNodes)
+	require.False(r.IsRegistered(peers[1]), "frozen peer should not be authenticated")
+
+	// Query errors should keep frozen nodes frozen.
+	failing[nodes[1].ID] = true
+	r.refreshFrozenNodes(ctx, nodes)
+	require.False(r.IsRegistered(peers[1]), "query errors should not unfreeze nodes")
+
+	// Frozen state should survive node list refreshes.
+	r.handleNodes(nodes, true)
+	require.False(r.IsRegistered(peers[1]), "frozen peer should stay frozen after refresh")
+
+	// Unfreeze events should unfreeze nodes immediately.
+	r.unfreezeNode(nodes[1].ID)
+	require.True(r.IsRegistered(peers[1]), "unfrozen peer should be authenticated")
+}
@@ -0,0 +1,94 @@
+package p2p
+
+import (
+	"net"
+
+	"github.com/libp2p/go-libp2p/core"
+	manet "github.com/multiformats/go-multiaddr/net"
+
+	"github.com/oasisprotocol/oasis-core/go/config"
+	p2pConfig "github.com/oasisprotocol/oasis-core/go/p2p/config"
+)
+
+// configWorker applies peer list changes whenever the node configuration is reloaded.
+func (p *p2p) configWorker() {
+	cfgCh, sub := config.WatchConfig()
+	defer sub.Close()
+
+	for {
+		select {
+		case <-p.ctx.Done():
+			return
+		case cfg := <-cfgCh:
+			if err := p.reloadPeerLists(&cfg.P2P); err != nil {
+				p.logger.Error("failed to reload peer lists",
+					"err", err,
+				)
+			}
+		}
+	}
+}
+
+// reloadPeerLists updates the persistent peers and blocked peer IPs to match the given
+// configuration.
+func (p *p2p) reloadPeerLists(cfg *p2pConfig.Config) error {
+	persistentPeers, err := parsePersistentPeers(cfg.ConnectionManager.PersistentPeers)
+	if err != nil {
+		return err
+	}
+	blockedPeers, err := parseBlockedPeerIPs(cfg.ConnectionGater.BlockedPeerIPs)
+	if err != nil {
+		return err
+	}
+
+	p.Lock()
+	defer p.Unlock()
+
+	cm := p.host.ConnManager()
+	newPersistentPeers := make(map[core.PeerID]struct{}, len(persistentPeers))
+	for _, peerID := range persistentPeers {
+		newPersistentPeers[peerID] = struct{}{}
+		cm.Protect(peerID, "")
+	}
+	for _, peerID := range p.persistentPeers {
+		if _, ok := newPersistentPeers[peerID]; !ok {
+			cm.Unprotect(peerID, "")
+		}
+	}
+
+	newBlockedPeers := make(map[string]struct{}, len(blockedPeers))
+	for _, ip := range blockedPeers {
+		newBlockedPeers[ip.String()] = struct{}{}
+		if err = p.gater.BlockAddr(ip); err != nil {
+			return err
+		}
+	}
+	for _, ip := range p.blockedPeers {
+		if _, ok := newBlockedPeers[ip.String()]; !ok {
+			if err = p.gater.UnblockAddr(ip); err != nil {
+				return err
+			}
+		}
+	}
+
+	// Disconnect peers that are connected from newly blocked addresses.
+	for _, conn := range p.host.Network().Conns() {
+		var ip net.IP
+		if ip, err = manet.ToIP(conn.RemoteMultiaddr()); err != nil {
+			continue
+		}
+		if _, ok := newBlockedPeers[ip.String()]; ok {
+			_ = conn.Close()
+		}
+	}
+
+	p.persistentPeers = persistentPeers
+	p.blockedPeers = blockedPeers
+
+	p.logger.Info("peer lists reloaded",
+		"num_persistent_peers", len(persistentPeers),
+		"num_blocked_peers", len(blockedPeers),
+	)
+
+	return nil
+}
@@ -456,6 +456,7 @@ func (c *client) timeCall(
 	start := time.Now()
 	err := c.call(ctx, peerID, request, rsp, maxPeerResponseTime)
 	latency := time.Since(start)
+	c.observeCallLatency(request.Method, latency, err)
 
 	if err != nil {
 		// If the caller canceled the context we should not degrade the peer.
@@ -620,6 +621,8 @@ func NewClient(h host.Host, p protocol.ID) Client {
 		return &nopClient{}
 	}
 
+	initMetrics()
+
 	return &client{
 		host:       h,
 		protocolID: p,
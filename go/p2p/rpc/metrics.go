@@ -0,0 +1,49 @@
+package rpc
+
+import (
+	"sync"
+	"time"
+
+	"github.com/prometheus/client_golang/prometheus"
+
+	"github.com/oasisprotocol/oasis-core/go/oasis-node/cmd/common/metrics"
+)
+
+var (
+	callLatency = prometheus.NewHistogramVec(
+		prometheus.HistogramOpts{
+			Name:    "oasis_p2p_rpc_call_latency",
+			Help:    "Latency of individual P2P RPC requests to peers (seconds).",
+			Buckets: []float64{0.01, 0.05, 0.1, 0.25, 0.5, 1, 2, 5, 10, 30, 60},
+		},
+		[]string{"protocol", "method", "result"},
+	)
+
+	rpcCollectors = []prometheus.Collector{
+		callLatency,
+	}
+
+	metricsOnce sync.Once
+)
+
+func initMetrics() {
+	metricsOnce.Do(func() {
+		prometheus.MustRegister(rpcCollectors...)
+	})
+}
+
+func (c *client) observeCallLatency(method string, latency time.Duration, err error) {
+	if !metrics.Enabled() {
+		return
+	}
+
+	result := "success"
+	if err != nil {
+		result = "failure"
+	}
+	callLatency.With(prometheus.Labels{
+		"protocol": string(c.protocolID),
+		"method":   method,
+		"result":   result,
+	}).Observe(latency.Seconds())
+}
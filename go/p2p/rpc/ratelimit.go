@@ -0,0 +1,95 @@
+package rpc
+
+import (
+	"sync"
+	"time"
+
+	"github.com/libp2p/go-libp2p/core"
+)
+
+// rateLimiterPruneInterval is the minimum interval between pruning idle buckets.
+const rateLimiterPruneInterval = time.Minute
+
+// peerRateLimiter is a per-peer token bucket rate limiter.
+type peerRateLimiter struct {
+	mu sync.Mutex
+
+	rate  float64
+	burst float64
+
+	buckets    map[core.PeerID]*tokenBucket
+	lastPruned time.Time
+	now        func() time.Time
+}
+
+type tokenBucket struct {
+	tokens  float64
+	updated time.Time
+}
+
+func newPeerRateLimiter(rate float64, burst int) *peerRateLimiter {
+	return &peerRateLimiter{
+		rate:       rate,
+		burst:      float64(burst),
+		buckets:    make(map[core.PeerID]*tokenBucket),
+		lastPruned: time.Now(),
+		now:        time.Now,
+	}
+}
+
+// SetLimits changes the rate and burst of the rate limiter. A zero rate disables rate limiting.
+func (l *peerRateLimiter) SetLimits(rate float64, burst int) {
+	l.mu.Lock()
+	defer l.mu.Unlock()
+
+	l.rate = rate
+	l.burst = float64(burst)
+	for _, b := range l.buckets {
+		if b.tokens > l.burst {
+			b.tokens = l.burst
+		}
+	}
+}
+
+// Allow returns true iff the given peer is allowed to make another request.
+func (l *peerRateLimiter) Allow(peerID core.PeerID) bool {
+	l.mu.Lock()
+	defer l.mu.Unlock()
+
+	if l.rate <= 0 {
+		return true
+	}
+
+	now := l.now()
+	if now.Sub(l.lastPruned) >= rateLimiterPruneInterval {
+		l.prune(now)
+	}
+
+	b, ok := l.buckets[peerID]
+	if !ok {
+		b = &tokenBucket{tokens: l.burst, updated: now}
+		l.buckets[peerID] = b
+	}
+
+	b.tokens += now.Sub(b.updated).Seconds() * l.rate
+	if b.tokens > l.burst {
+		b.tokens = l.burst
+	}
+	b.updated = now
+
+	if b.tokens < 1 {
+		return false
+	}
+	b.tokens--
+	return true
+}
+
+// prune removes buckets that have been fully replenished and carry no state.
+func (l *peerRateLimiter) prune(now time.Time) {
+	for peerID, b := range l.buckets {
+		if b.tokens+now.Sub(b.updated).Seconds()*l.rate >= l.burst {
+			delete(l.buckets, peerID)
+		}
+	}
+	l.lastPruned = now
+}
@@ -0,0 +1,51 @@
+package rpc
+
+import (
+	"testing"
+	"time"
+
+	"github.com/libp2p/go-libp2p/core"
+	"github.com/stretchr/testify/require"
+)
+
+func TestPeerRateLimiter(t *testing.T) {
+	require := require.New(t)
+
+	now := time.Unix(1_000_000, 0)
+	l := newPeerRateLimiter(1, 2)
+	l.now = func() time.Time { return now }
+	l.lastPruned = now
+
+	peerA := core.PeerID("a")
+	peerB := core.PeerID("b")
+
+	// Burst should be allowed.
+	require.True(l.Allow(peerA))
+	require.True(l.Allow(peerA))
+	require.False(l.Allow(peerA), "requests over burst should be rejected")
+
+	// Other peers should not be affected.
+	require.True(l.Allow(peerB))
+
+	// Tokens should replenish over time.
+	now = now.Add(time.Second)
+	require.True(l.Allow(peerA))
+	require.False(l.Allow(peerA))
+
+	// Fully replenished buckets should only be pruned periodically.
+	now = now.Add(10 * time.Second)
+	require.True(l.Allow(peerB))
+	require.Len(l.buckets, 2)
+
+	now = now.Add(rateLimiterPruneInterval)
+	require.True(l.Allow(peerB))
+	require.Len(l.buckets, 1)
+
+	// Limits should be adjustable at runtime.
+	l.SetLimits(1, 1)
+	require.True(l.Allow(peerA))
+	require.False(l.Allow(peerA), "requests over new burst should be rejected")
+
+	l.SetLimits(0, 0)
+	require.True(l.Allow(peerA), "zero rate should disable rate limiting")
+}
@@ -2,6 +2,7 @@ package rpc
 
 import (
 	"context"
+	"sync"
 	"time"
 
 	"github.com/libp2p/go-libp2p/core"
@@ -12,6 +13,8 @@ import (
 	"github.com/oasisprotocol/oasis-core/go/common/cbor"
 	"github.com/oasisprotocol/oasis-core/go/common/errors"
 	"github.com/oasisprotocol/oasis-core/go/common/logging"
+	"github.com/oasisprotocol/oasis-core/go/common/pubsub"
+	"github.com/oasisprotocol/oasis-core/go/config"
 )
 
 const (
@@ -32,12 +35,60 @@ type Server interface {
 
 	// HandleStream handles an incoming stream.
 	HandleStream(stream network.Stream)
+
+	// Close stops any background workers of the server.
+	Close()
+}
+
+// ServerOption is a configuration option for the RPC server.
+type ServerOption func(s *server)
+
+// WithRateLimit configures a per-peer rate limit for incoming requests.
+//
+// Each peer may issue up to burst requests at once, after which requests are replenished at the
+// given rate (in requests per second).
+func WithRateLimit(rate float64, burst int) ServerOption {
+	return func(s *server) {
+		s.limiter = newPeerRateLimiter(rate, burst)
+	}
+}
+
+// WithRequestHandleTimeout configures the maximum amount of time that can be spent on handling
+// a request.
+func WithRequestHandleTimeout(timeout time.Duration) ServerOption {
+	return func(s *server) {
+		s.handleTimeout = timeout
+	}
+}
+
+// ServerOptionsFromConfig returns the server options configured in the node configuration.
+//
+// The rate limits of servers created with these options follow any configuration reloads.
+func ServerOptionsFromConfig() []ServerOption {
+	cfg := config.GlobalConfig.P2P.RPC
+
+	opts := []ServerOption{
+		func(s *server) {
+			s.limiter = newPeerRateLimiter(cfg.RateLimit, cfg.RateLimitBurst)
+			cfgCh, sub := config.WatchConfig()
+			go s.watchRateLimits(cfgCh, sub)
+		},
+	}
+	if cfg.RequestHandleTimeout > 0 {
+		opts = append(opts, WithRequestHandleTimeout(cfg.RequestHandleTimeout))
+	}
+	return opts
 }
 
 type server struct {
 	Service
 
-	protocolID protocol.ID
+	protocolID    protocol.ID
+	limiter       *peerRateLimiter
+	handleTimeout time.Duration
+
+	closeOnce sync.Once
+	quitCh    chan struct{}
 
 	logger *logging.Logger
 }
@@ -46,6 +97,27 @@ func (s *server) Protocol() protocol.ID {
 	return s.protocolID
 }
 
+func (s *server) Close() {
+	s.closeOnce.Do(func() {
+		close(s.quitCh)
+	})
+}
+
+// watchRateLimits updates the limits of the server's rate limiter whenever the configuration
+// is reloaded, until the server is closed.
+func (s *server) watchRateLimits(cfgCh <-chan *config.Config, sub pubsub.ClosableSubscription) {
+	defer sub.Close()
+
+	for {
+		select {
+		case <-s.quitCh:
+			return
+		case cfg := <-cfgCh:
+			s.limiter.SetLimits(cfg.P2P.RPC.RateLimit, cfg.P2P.RPC.RateLimitBurst)
+		}
+	}
+}
+
 func (s *server) HandleStream(stream network.Stream) {
 	defer stream.Close()
 
@@ -74,10 +146,18 @@ func (s *server) HandleStream(stream network.Stream) {
 	}
 
 	// Handle request.
-	ctx, cancel := context.WithTimeout(context.Background(), RequestHandleTimeout)
-	ctx = WithPeerAddrInfo(ctx, addr)
-	rsp, err := s.HandleRequest(ctx, request.Method, request.Body)
-	cancel()
+	var (
+		rsp interface{}
+		err error
+	)
+	if s.limiter != nil && !s.limiter.Allow(addr.ID) {
+		err = ErrRateLimited
+	} else {
+		ctx, cancel := context.WithTimeout(context.Background(), s.handleTimeout)
+		ctx = WithPeerAddrInfo(ctx, addr)
+		rsp, err = s.HandleRequest(ctx, request.Method, request.Body)
+		cancel()
+	}
 
 	// Generate response.
 	var response Response
@@ -110,10 +190,16 @@ func (s *server) HandleStream(stream network.Stream) {
 }
 
 // NewServer creates a new RPC server for the given protocol.
-func NewServer(protocolID protocol.ID, srv Service) Server {
-	return &server{
-		Service:    srv,
-		protocolID: protocolID,
-		logger:     logging.GetLogger("p2p/rpc/server").With("protocol", protocolID),
+func NewServer(protocolID protocol.ID, srv Service, opts ...ServerOption) Server {
+	s := &server{
+		Service:       srv,
+		protocolID:    protocolID,
+		handleTimeout: RequestHandleTimeout,
+		quitCh:        make(chan struct{}),
+		logger:        logging.GetLogger("p2p/rpc/server").With("protocol", protocolID),
+	}
+	for _, opt := range opts {
+		opt(s)
 	}
+	return s
 }
@@ -16,6 +16,9 @@ var (
 
 	// ErrBadRequest is an error raised when a given request is malformed.
 	ErrBadRequest = errors.New(ModuleName, 2, "rpc: bad request")
+
+	// ErrRateLimited is an error raised when a peer exceeds the configured request rate.
+	ErrRateLimited = errors.New(ModuleName, 3, "rpc: rate limited")
 )
 
 // Request is a request sent by the client.
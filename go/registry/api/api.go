@@ -252,6 +252,23 @@ type GetRuntimeQuery struct {
 type GetRuntimesQuery struct {
 	Height           int64 `json:"height"`
 	IncludeSuspended bool  `json:"include_suspended"`
+
+	// EntityID is an optional filter that only matches runtimes owned by the given entity.
+	EntityID *signature.PublicKey `json:"entity_id,omitempty"`
+	// TEEHardware is an optional filter that only matches runtimes requiring the given
+	// TEE hardware.
+	TEEHardware *node.TEEHardware `json:"tee_hardware,omitempty"`
+}
+
+// Matches returns true iff the given runtime satisfies the query filters.
+func (q *GetRuntimesQuery) Matches(rt *Runtime) bool {
+	if q.EntityID != nil && !rt.EntityID.Equal(*q.EntityID) {
+		return false
+	}
+	if q.TEEHardware != nil && rt.TEEHardware != *q.TEEHardware {
+		return false
+	}
+	return true
 }
 
 // ConsensusAddressQuery is a registry query by consensus address.
@@ -352,3 +352,34 @@ func TestVerifyNodeUpdate(t *testing.T) {
 		require.Equal(t, tc.err, err, tc.msg)
 	}
 }
+
+func TestGetRuntimesQueryMatches(t *testing.T) {
+	require := require.New(t)
+
+	var entityA, entityB signature.PublicKey
+	require.NoError(entityA.UnmarshalHex("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"), "UnmarshalHex")
+	require.NoError(entityB.UnmarshalHex("bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"), "UnmarshalHex")
+	sgx := node.TEEHardwareIntelSGX
+
+	rt := &Runtime{
+		EntityID:    entityA,
+		TEEHardware: node.TEEHardwareIntelSGX,
+	}
+
+	for _, tc := range []struct {
+		query   GetRuntimesQuery
+		matches bool
+		msg     string
+	}{
+		{GetRuntimesQuery{}, true, "empty query should match"},
+		{GetRuntimesQuery{EntityID: &entityA}, true, "matching entity should match"},
+		{GetRuntimesQuery{EntityID: &entityB}, false, "different entity should not match"},
+		{GetRuntimesQuery{TEEHardware: &sgx}, true, "matching TEE hardware should match"},
+		{GetRuntimesQuery{EntityID: &entityB, TEEHardware: &sgx}, false, "all filters should be applied"},
+	} {
+		require.Equal(tc.matches, tc.query.Matches(rt), tc.msg)
+	}
+
+	rt.TEEHardware = node.TEEHardwareInvalid
+	require.False((&GetRuntimesQuery{TEEHardware: &sgx}).Matches(rt), "runtime without TEE should not match")
+}
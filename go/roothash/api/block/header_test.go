@@ -117,3 +117,24 @@ func TestTimestamp(t *testing.T) {
 		}
 	}
 }
+
+func BenchmarkHeaderEncodedHash(b *testing.B) {
+	var emptyRoot hash.Hash
+	emptyRoot.Empty()
+
+	header := Header{
+		Version:      42,
+		Round:        1000,
+		Timestamp:    1560257841,
+		HeaderType:   Normal,
+		PreviousHash: emptyRoot,
+		IORoot:       emptyRoot,
+		StateRoot:    emptyRoot,
+		MessagesHash: emptyRoot,
+	}
+
+	b.ResetTimer()
+	for n := 0; n < b.N; n++ {
+		_ = header.EncodedHash()
+	}
+}
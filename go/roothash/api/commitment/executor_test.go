@@ -1,12 +1,15 @@
 package commitment
 
 import (
+	"crypto/rand"
 	"testing"
 
 	"github.com/stretchr/testify/require"
 
+	"github.com/oasisprotocol/oasis-core/go/common"
 	"github.com/oasisprotocol/oasis-core/go/common/crypto/hash"
 	"github.com/oasisprotocol/oasis-core/go/common/crypto/signature"
+	memorySigner "github.com/oasisprotocol/oasis-core/go/common/crypto/signature/signers/memory"
 	"github.com/oasisprotocol/oasis-core/go/roothash/api/message"
 )
 
@@ -177,3 +180,37 @@ func TestValidateBasic(t *testing.T) {
 		}
 	}
 }
+
+func BenchmarkExecutorCommitmentVerify(b *testing.B) {
+	var emptyRoot hash.Hash
+	emptyRoot.Empty()
+
+	var runtimeID common.Namespace
+	_ = runtimeID.UnmarshalHex("8000000000000000000000000000000000000000000000000000000000000000")
+
+	signer, err := memorySigner.NewSigner(rand.Reader)
+	require.NoError(b, err, "NewSigner")
+
+	ec := ExecutorCommitment{
+		NodeID: signer.Public(),
+		Header: ExecutorCommitmentHeader{
+			Header: ComputeResultsHeader{
+				Round:          42,
+				PreviousHash:   emptyRoot,
+				IORoot:         &emptyRoot,
+				StateRoot:      &emptyRoot,
+				MessagesHash:   &emptyRoot,
+				InMessagesHash: &emptyRoot,
+			},
+		},
+	}
+	err = ec.Sign(signer, runtimeID)
+	require.NoError(b, err, "Sign")
+
+	b.ResetTimer()
+	for n := 0; n < b.N; n++ {
+		if err = ec.Verify(runtimeID); err != nil {
+			b.Fatalf("Verify: %v", err)
+		}
+	}
+}
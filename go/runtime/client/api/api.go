@@ -34,6 +34,10 @@ var (
 	ErrCheckTxFailed = errors.New(ModuleName, 5, "client: transaction check failed")
 	// ErrNoHostedRuntime is returned when the hosted runtime is not available locally.
 	ErrNoHostedRuntime = errors.New(ModuleName, 6, "client: no hosted runtime is available")
+	// ErrTxTooLarge is an error returned when the submitted transaction exceeds the size limit.
+	ErrTxTooLarge = errors.New(ModuleName, 7, "client: transaction too large")
+	// ErrMalformedTx is an error returned when the submitted transaction is not well-formed.
+	ErrMalformedTx = errors.New(ModuleName, 8, "client: malformed transaction")
 )
 
 // RuntimeClient is the runtime client interface.
@@ -0,0 +1,97 @@
+package api
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/oasisprotocol/oasis-core/go/common"
+	"github.com/oasisprotocol/oasis-core/go/common/pubsub"
+	"github.com/oasisprotocol/oasis-core/go/roothash/api/block"
+)
+
+// WatchBlocksFrom subscribes to blocks of the given runtime, starting at the given round.
+//
+// Blocks that have already been finalized are fetched from the runtime history before live
+// blocks are delivered. Every round is delivered exactly once and in order, so a consumer that
+// persists the last processed round can resume after a restart by subscribing from the next
+// round.
+//
+// The returned block channel is closed when the subscription is closed or when a block cannot
+// be fetched (e.g., because it has been pruned from history) or the live subscription ends. In
+// the latter cases the reason is sent on the returned error channel before the block channel is
+// closed, and the consumer should resubscribe from its last processed round. The error channel
+// is closed after the block channel.
+func WatchBlocksFrom(
+	ctx context.Context,
+	client RuntimeClient,
+	runtimeID common.Namespace,
+	round uint64,
+) (<-chan *block.Block, <-chan error, pubsub.ClosableSubscription, error) {
+	// Subscribe first so that no blocks are missed while catching up.
+	liveCh, liveSub, err := client.WatchBlocks(ctx, runtimeID)
+	if err != nil {
+		return nil, nil, nil, err
+	}
+
+	ctx, sub := pubsub.NewContextSubscription(ctx)
+	ch := make(chan *block.Block)
+	errCh := make(chan error, 1)
+
+	go func() {
+		defer close(errCh)
+		defer close(ch)
+		defer liveSub.Close()
+
+		next := round
+		deliver := func(blk *block.Block) bool {
+			select {
+			case ch <- blk:
+				next = blk.Header.Round + 1
+				return true
+			case <-ctx.Done():
+				return false
+			}
+		}
+		catchUp := func(target uint64) bool {
+			for next < target {
+				blk, err := client.GetBlock(ctx, &GetBlockRequest{RuntimeID: runtimeID, Round: next})
+				if err != nil {
+					if ctx.Err() == nil {
+						errCh <- fmt.Errorf("failed to fetch block for round %d: %w", next, err)
+					}
+					return false
+				}
+				if !deliver(blk) {
+					return false
+				}
+			}
+			return true
+		}
+
+		for {
+			select {
+			case annBlk, ok := <-liveCh:
+				if !ok {
+					errCh <- fmt.Errorf("live block subscription closed")
+					return
+				}
+
+				blk := annBlk.Block
+				if blk.Header.Round < next {
+					// Already delivered.
+					continue
+				}
+				if !catchUp(blk.Header.Round) {
+					return
+				}
+				if !deliver(blk) {
+					return
+				}
+			case <-ctx.Done():
+				return
+			}
+		}
+	}()
+
+	return ch, errCh, sub, nil
+}
@@ -0,0 +1,83 @@
+package api
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+
+	"github.com/oasisprotocol/oasis-core/go/common"
+	"github.com/oasisprotocol/oasis-core/go/common/pubsub"
+	roothash "github.com/oasisprotocol/oasis-core/go/roothash/api"
+	"github.com/oasisprotocol/oasis-core/go/roothash/api/block"
+)
+
+type mockWatchClient struct {
+	RuntimeClient
+
+	history map[uint64]*block.Block
+	liveCh  chan *roothash.AnnotatedBlock
+}
+
+func (c *mockWatchClient) GetBlock(_ context.Context, request *GetBlockRequest) (*block.Block, error) {
+	blk, ok := c.history[request.Round]
+	if !ok {
+		return nil, ErrNotFound
+	}
+	return blk, nil
+}
+
+func (c *mockWatchClient) WatchBlocks(ctx context.Context, _ common.Namespace) (<-chan *roothash.AnnotatedBlock, pubsub.ClosableSubscription, error) {
+	_, sub := pubsub.NewContextSubscription(ctx)
+	return c.liveCh, sub, nil
+}
+
+func newTestBlock(round uint64) *block.Block {
+	var blk block.Block
+	blk.Header.Round = round
+	return &blk
+}
+
+func TestWatchBlocksFrom(t *testing.T) {
+	require := require.New(t)
+
+	client := &mockWatchClient{
+		history: make(map[uint64]*block.Block),
+		liveCh:  make(chan *roothash.AnnotatedBlock, 10),
+	}
+	for round := uint64(0); round <= 10; round++ {
+		client.history[round] = newTestBlock(round)
+	}
+
+	// The latest block is replayed on subscription, followed by a duplicate and a gap.
+	for _, round := range []uint64{7, 8, 8, 10} {
+		client.liveCh <- &roothash.AnnotatedBlock{Block: client.history[round]}
+	}
+
+	ch, errCh, sub, err := WatchBlocksFrom(context.Background(), client, common.Namespace{}, 3)
+	require.NoError(err, "WatchBlocksFrom")
+	defer sub.Close()
+
+	for expected := uint64(3); expected <= 10; expected++ {
+		select {
+		case blk := <-ch:
+			require.EqualValues(expected, blk.Header.Round, "rounds should be delivered exactly once and in order")
+		case <-time.After(time.Second):
+			t.Fatalf("failed to receive block for round %d", expected)
+		}
+	}
+
+	// Missing history (round 11) should report an error and close the channel.
+	client.liveCh <- &roothash.AnnotatedBlock{Block: newTestBlock(12)}
+	select {
+	case _, ok := <-ch:
+		require.False(ok, "channel should be closed")
+	case <-time.After(time.Second):
+		t.Fatalf("channel should be closed")
+	}
+	err = <-errCh
+	require.ErrorIs(err, ErrNotFound, "missing history should be reported")
+	_, ok := <-errCh
+	require.False(ok, "error channel should be closed")
+}
@@ -94,6 +94,36 @@ type Config struct {
 	// AttestInterval is the interval for periodic runtime re-attestation. If not specified
 	// a default will be used.
 	AttestInterval time.Duration `yaml:"attest_interval,omitempty"`
+
+	// QueryCacheSize is the maximum total size (in bytes) of runtime query responses cached by
+	// client nodes for each runtime. Zero disables the cache.
+	QueryCacheSize uint64 `yaml:"query_cache_size,omitempty"`
+
+	// Validation of transactions submitted via client nodes.
+	TxValidation TxValidationConfig `yaml:"tx_validation,omitempty"`
+
+	// Runtime liveness watchdog configuration.
+	Watchdog WatchdogConfig `yaml:"watchdog,omitempty"`
+}
+
+// WatchdogConfig is the runtime liveness watchdog configuration.
+type WatchdogConfig struct {
+	// Interval at which runtimes are pinged to check liveness. Zero disables the watchdog.
+	Interval time.Duration `yaml:"interval"`
+	// Time a runtime has to respond to a liveness ping.
+	PingTimeout time.Duration `yaml:"ping_timeout"`
+	// Number of consecutive failed liveness pings after which a runtime is restarted.
+	MaxFailures int `yaml:"max_failures"`
+}
+
+// TxValidationConfig is the configuration of checks performed on transactions submitted via
+// client nodes before they are queued for checks by the runtime.
+type TxValidationConfig struct {
+	// MaxTxSize is the maximum size of a transaction in bytes. Transactions larger than the
+	// runtime's maximum batch size are always rejected. Zero means no additional limit.
+	MaxTxSize uint64 `yaml:"max_tx_size,omitempty"`
+	// StrictCBOR requires transactions to be well-formed CBOR.
+	StrictCBOR bool `yaml:"strict_cbor,omitempty"`
 }
 
 // PruneConfig is the history pruner configuration structure.
@@ -130,6 +160,17 @@ func (c *Config) Validate() error {
 		return fmt.Errorf("unknown runtime environment: %s", c.Environment)
 	}
 
+	switch {
+	case c.Watchdog.Interval < 0:
+		return fmt.Errorf("watchdog.interval must be >= 0")
+	case c.Watchdog.Interval == 0:
+		// Watchdog is disabled.
+	case c.Watchdog.PingTimeout <= 0:
+		return fmt.Errorf("watchdog.ping_timeout must be > 0")
+	case c.Watchdog.MaxFailures < 1:
+		return fmt.Errorf("watchdog.max_failures must be >= 1")
+	}
+
 	switch c.Prune.Strategy {
 	case "none":
 	case "keep_last":
@@ -166,5 +207,10 @@ func DefaultConfig() Config {
 			RepublishInterval:    60 * time.Second,
 		},
 		PreWarmEpochs: 3,
+		Watchdog: WatchdogConfig{
+			Interval:    1 * time.Minute,
+			PingTimeout: 10 * time.Second,
+			MaxFailures: 3,
+		},
 	}
 }
@@ -317,6 +317,10 @@ type CheckTxMetadata struct {
 	// sequence number must be lower than or equal to SenderSeq.
 	SenderStateSeq uint64 `json:"sender_state_seq,omitempty"`
 
+	// NotAfter is the last runtime round in which the transaction may be scheduled. After this
+	// round the transaction is evicted from the transaction pool. Zero means no expiry.
+	NotAfter uint64 `json:"not_after,omitempty"`
+
 	// Fields below are deprecated to avoid breaking protocol changes. They may be removed once
 	// all runtimes stop sending those fields.
 
@@ -0,0 +1,46 @@
+package sandbox
+
+import (
+	"sync"
+
+	"github.com/prometheus/client_golang/prometheus"
+
+	"github.com/oasisprotocol/oasis-core/go/oasis-node/cmd/common/metrics"
+)
+
+var (
+	// Number of runtime restarts due to failed liveness pings.
+	watchdogKills = prometheus.NewCounterVec(
+		prometheus.CounterOpts{
+			Name: "oasis_runtime_watchdog_kills",
+			Help: "Number of runtimes killed by the watchdog due to failed liveness pings.",
+		},
+		[]string{"runtime"},
+	)
+
+	sandboxCollectors = []prometheus.Collector{
+		watchdogKills,
+	}
+
+	metricsOnce sync.Once
+)
+
+// updateWatchdogMetrics updates the watchdog metrics if metrics are enabled.
+func updateWatchdogMetrics(runtime string) {
+	if !metrics.Enabled() {
+		return
+	}
+
+	watchdogKills.With(prometheus.Labels{"runtime": runtime}).Inc()
+}
+
+// initMetrics registers the metrics collectors if metrics are enabled.
+func initMetrics() {
+	if !metrics.Enabled() {
+		return
+	}
+
+	metricsOnce.Do(func() {
+		prometheus.MustRegister(sandboxCollectors...)
+	})
+}
@@ -60,6 +60,44 @@ type Config struct {
 
 	// InsecureNoSandbox disables the sandbox and runs the runtime binary directly.
 	InsecureNoSandbox bool
+
+	// Watchdog is the runtime liveness watchdog configuration.
+	Watchdog WatchdogConfig
+}
+
+// WatchdogConfig contains the runtime liveness watchdog configuration options.
+type WatchdogConfig struct {
+	// Interval is the interval at which the runtime is pinged to check liveness. Zero disables
+	// the watchdog.
+	Interval time.Duration
+
+	// PingTimeout is the time the runtime has to respond to a liveness ping.
+	PingTimeout time.Duration
+
+	// MaxFailures is the number of consecutive failed liveness pings after which the runtime is
+	// considered stalled and is restarted.
+	MaxFailures int
+}
+
+// watchdog tracks consecutive failed runtime liveness pings.
+type watchdog struct {
+	cfg      WatchdogConfig
+	failures int
+}
+
+// reset resets the number of consecutive failed pings.
+func (w *watchdog) reset() {
+	w.failures = 0
+}
+
+// pingFailed records a failed ping and returns true iff the runtime should be restarted.
+func (w *watchdog) pingFailed() bool {
+	w.failures++
+	if w.failures < w.cfg.MaxFailures {
+		return false
+	}
+	w.failures = 0
+	return true
 }
 
 // HostInitializerParams contains parameters for the HostInitializer function.
@@ -91,6 +129,7 @@ func (p *provisioner) NewRuntime(cfg host.Config) (host.Runtime, error) {
 		notifyUpdateCapabilityTEECh: make(chan struct{}, 1),
 		logger:                      p.cfg.Logger.With("runtime_id", id),
 	}
+	r.spawn = r.startProcess
 
 	return r, nil
 }
@@ -100,6 +139,12 @@ func (p *provisioner) Name() string {
 	return "sandbox"
 }
 
+// pingResult is the result of a watchdog liveness ping.
+type pingResult struct {
+	process process.Process
+	err     error
+}
+
 // abortRequest is a request to the runtime manager goroutine to abort the runtime.
 // In case of failures or if force flag is set, the runtime is restarted.
 type abortRequest struct {
@@ -119,6 +164,7 @@ type sandboxedRuntime struct {
 	ctrlCh chan interface{}
 
 	started  bool
+	spawn    func() error
 	process  process.Process
 	conn     protocol.Connection
 	notifier *pubsub.Broker
@@ -477,9 +523,28 @@ func (r *sandboxedRuntime) handleAbortRequest(rq *abortRequest) error {
 	return nil
 }
 
+func (r *sandboxedRuntime) ping(proc process.Process, conn protocol.Connection, ch chan<- *pingResult) {
+	ctx, cancel := context.WithTimeout(context.Background(), r.cfg.Watchdog.PingTimeout)
+	defer cancel()
+
+	_, err := conn.Call(ctx, &protocol.Body{RuntimePingRequest: &protocol.Empty{}})
+	ch <- &pingResult{process: proc, err: err}
+}
+
 func (r *sandboxedRuntime) manager() {
 	var ticker *backoff.Ticker
 
+	// Periodically ping the runtime so that a stalled runtime gets restarted.
+	var watchdogCh <-chan time.Time
+	if r.cfg.Watchdog.Interval > 0 {
+		watchdogTicker := time.NewTicker(r.cfg.Watchdog.Interval)
+		defer watchdogTicker.Stop()
+		watchdogCh = watchdogTicker.C
+	}
+	wd := watchdog{cfg: r.cfg.Watchdog}
+	pingCh := make(chan *pingResult, 1)
+	var pingInFlight bool
+
 	defer func() {
 		r.logger.Warn("terminating runtime")
 
@@ -542,7 +607,7 @@ func (r *sandboxedRuntime) manager() {
 				"attempt", attempt,
 			)
 
-			if err := r.startProcess(); err != nil {
+			if err := r.spawn(); err != nil {
 				r.logger.Error("failed to start runtime",
 					"err", err,
 				)
@@ -556,6 +621,7 @@ func (r *sandboxedRuntime) manager() {
 
 				continue
 			}
+			wd.reset()
 		}
 
 		// Wait for either the runtime or the runtime manager to terminate.
@@ -596,6 +662,37 @@ func (r *sandboxedRuntime) manager() {
 				ticker.Stop()
 				ticker = nil
 			}
+		case <-watchdogCh:
+			if pingInFlight {
+				continue
+			}
+			pingInFlight = true
+			go r.ping(r.process, r.conn, pingCh)
+		case res := <-pingCh:
+			pingInFlight = false
+			if res.process != r.process {
+				// Stale result for a previous process.
+				continue
+			}
+			if res.err == nil {
+				wd.reset()
+				continue
+			}
+
+			r.logger.Warn("runtime liveness ping failed",
+				"err", res.err,
+				"failures", wd.failures+1,
+			)
+			if !wd.pingFailed() {
+				continue
+			}
+
+			// Kill the stalled process, it will be restarted once it terminates.
+			r.logger.Error("runtime is not responding, restarting",
+				"failures", r.cfg.Watchdog.MaxFailures,
+			)
+			updateWatchdogMetrics(r.id.String())
+			r.process.Kill()
 		case ev := <-evCh:
 			// Update runtime's CapabilityTEE in case this is an update event.
 			if ue := ev.Updated; ue != nil {
@@ -609,6 +706,8 @@ func (r *sandboxedRuntime) manager() {
 
 // New creates a new runtime provisioner that uses a local process sandbox.
 func New(cfg Config) (host.Provisioner, error) {
+	initMetrics()
+
 	// Use a default Logger if none was provided.
 	if cfg.Logger == nil {
 		cfg.Logger = logging.GetLogger("runtime/host/sandbox")
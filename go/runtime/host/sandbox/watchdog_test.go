@@ -0,0 +1,185 @@
+package sandbox
+
+import (
+	"context"
+	"net"
+	"sync"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+
+	"github.com/oasisprotocol/oasis-core/go/common"
+	"github.com/oasisprotocol/oasis-core/go/common/logging"
+	"github.com/oasisprotocol/oasis-core/go/common/pubsub"
+	"github.com/oasisprotocol/oasis-core/go/common/version"
+	"github.com/oasisprotocol/oasis-core/go/runtime/host"
+	"github.com/oasisprotocol/oasis-core/go/runtime/host/protocol"
+)
+
+const watchdogRecvTimeout = 5 * time.Second
+
+// stalledConnection is a mock connection that stops answering pings once stalled.
+type stalledConnection struct {
+	stalled atomic.Bool
+}
+
+func (c *stalledConnection) Close() {}
+
+func (c *stalledConnection) GetInfo() (*protocol.RuntimeInfoResponse, error) {
+	return nil, nil
+}
+
+func (c *stalledConnection) Call(ctx context.Context, _ *protocol.Body) (*protocol.Body, error) {
+	if !c.stalled.Load() {
+		return &protocol.Body{Empty: &protocol.Empty{}}, nil
+	}
+	<-ctx.Done()
+	return nil, ctx.Err()
+}
+
+func (c *stalledConnection) InitHost(context.Context, net.Conn, *protocol.HostInfo) (*version.Version, error) {
+	return nil, nil
+}
+
+func (c *stalledConnection) InitGuest(net.Conn) error {
+	return nil
+}
+
+// fakeProcess is a mock process that terminates when killed.
+type fakeProcess struct {
+	waitCh chan struct{}
+	once   sync.Once
+	killed atomic.Bool
+}
+
+func newFakeProcess() *fakeProcess {
+	return &fakeProcess{waitCh: make(chan struct{})}
+}
+
+func (p *fakeProcess) GetPID() int {
+	return 0
+}
+
+func (p *fakeProcess) Wait() <-chan struct{} {
+	return p.waitCh
+}
+
+func (p *fakeProcess) Error() error {
+	return nil
+}
+
+func (p *fakeProcess) Kill() {
+	p.killed.Store(true)
+	p.once.Do(func() {
+		close(p.waitCh)
+	})
+}
+
+func TestWatchdog(t *testing.T) {
+	require := require.New(t)
+
+	r := &sandboxedRuntime{
+		cfg: Config{
+			Watchdog: WatchdogConfig{
+				Interval:    time.Millisecond,
+				PingTimeout: 10 * time.Millisecond,
+				MaxFailures: 3,
+			},
+		},
+	}
+	wd := watchdog{cfg: r.cfg.Watchdog}
+	conn := &stalledConnection{}
+	pingCh := make(chan *pingResult, 1)
+
+	ping := func() error {
+		r.ping(nil, conn, pingCh)
+		return (<-pingCh).err
+	}
+
+	// A responsive runtime should not be restarted.
+	require.NoError(ping(), "ping should succeed")
+	wd.reset()
+
+	// A stalled runtime should only be restarted after the configured number of failures.
+	conn.stalled.Store(true)
+	for i := 1; i < r.cfg.Watchdog.MaxFailures; i++ {
+		require.Error(ping(), "ping should time out")
+		require.False(wd.pingFailed(), "runtime should not be restarted after %d failures", i)
+	}
+	require.Error(ping(), "ping should time out")
+	require.True(wd.pingFailed(), "runtime should be restarted after max failures")
+	require.Zero(wd.failures, "failures should be reset after restart")
+
+	// Successful pings should reset the failure count.
+	require.False(wd.pingFailed())
+	wd.reset()
+	require.False(wd.pingFailed())
+	require.False(wd.pingFailed())
+	require.True(wd.pingFailed())
+}
+
+func TestWatchdogRestart(t *testing.T) {
+	require := require.New(t)
+
+	r := &sandboxedRuntime{
+		cfg: Config{
+			Watchdog: WatchdogConfig{
+				Interval:    10 * time.Millisecond,
+				PingTimeout: 10 * time.Millisecond,
+				MaxFailures: 2,
+			},
+		},
+		id:       common.NewTestNamespaceFromSeed([]byte("sandbox watchdog test"), 0),
+		stopCh:   make(chan struct{}),
+		quitCh:   make(chan struct{}),
+		ctrlCh:   make(chan interface{}, ctrlChannelBufferSize),
+		notifier: pubsub.NewBroker(false),
+		logger:   logging.GetLogger("runtime/host/sandbox/test"),
+	}
+
+	// The first process stalls, while the restarted one responds to pings.
+	var procs []*fakeProcess
+	r.spawn = func() error {
+		conn := &stalledConnection{}
+		conn.stalled.Store(len(procs) == 0)
+
+		p := newFakeProcess()
+		procs = append(procs, p)
+		r.process = p
+		r.Lock()
+		r.conn = conn
+		r.Unlock()
+
+		r.notifier.Broadcast(&host.Event{Started: &host.StartedEvent{}})
+		return nil
+	}
+
+	evCh, sub, err := r.WatchEvents(context.Background())
+	require.NoError(err, "WatchEvents")
+	defer sub.Close()
+
+	require.NoError(r.Start(), "Start")
+
+	nextEvent := func() *host.Event {
+		select {
+		case ev := <-evCh:
+			return ev
+		case <-time.After(watchdogRecvTimeout):
+			t.Fatalf("failed to receive runtime event")
+			return nil
+		}
+	}
+
+	require.NotNil(nextEvent().Started, "runtime should be started")
+	require.NotNil(nextEvent().Stopped, "stalled runtime should be stopped")
+	require.NotNil(nextEvent().Started, "stalled runtime should be restarted")
+
+	r.Stop()
+	require.NotNil(nextEvent().Stopped, "runtime should be stopped")
+	<-r.quitCh
+
+	require.Len(procs, 2, "runtime should be restarted exactly once")
+	require.True(procs[0].killed.Load(), "stalled process should be killed")
+}
@@ -76,6 +76,9 @@ type Config struct {
 
 	// InsecureNoSandbox disables the sandbox and runs the loader directly.
 	InsecureNoSandbox bool
+
+	// Watchdog is the runtime liveness watchdog configuration.
+	Watchdog sandbox.WatchdogConfig
 }
 
 // RuntimeExtra is the extra configuration for SGX runtimes.
@@ -457,6 +460,7 @@ func New(cfg Config) (host.Provisioner, error) {
 		HostInitializer:   s.hostInitializer,
 		InsecureNoSandbox: cfg.InsecureNoSandbox,
 		Logger:            s.logger,
+		Watchdog:          cfg.Watchdog,
 	})
 	if err != nil {
 		return nil, err
@@ -144,11 +144,18 @@ func newConfig(dataDir string, commonStore *persistent.CommonStore, consensus co
 				}
 			}
 
+			watchdogCfg := hostSandbox.WatchdogConfig{
+				Interval:    config.GlobalConfig.Runtime.Watchdog.Interval,
+				PingTimeout: config.GlobalConfig.Runtime.Watchdog.PingTimeout,
+				MaxFailures: config.GlobalConfig.Runtime.Watchdog.MaxFailures,
+			}
+
 			// Configure the non-TEE provisioner.
 			rh.Provisioners[node.TEEHardwareInvalid], err = hostSandbox.New(hostSandbox.Config{
 				HostInfo:          hostInfo,
 				InsecureNoSandbox: insecureNoSandbox,
 				SandboxBinaryPath: sandboxBinary,
+				Watchdog:          watchdogCfg,
 			})
 			if err != nil {
 				return nil, fmt.Errorf("failed to create runtime provisioner: %w", err)
@@ -162,6 +169,7 @@ func newConfig(dataDir string, commonStore *persistent.CommonStore, consensus co
 					HostInfo:          hostInfo,
 					InsecureNoSandbox: insecureNoSandbox,
 					SandboxBinaryPath: sandboxBinary,
+					Watchdog:          watchdogCfg,
 				})
 				if err != nil {
 					return nil, fmt.Errorf("failed to create runtime provisioner: %w", err)
@@ -192,6 +200,7 @@ func newConfig(dataDir string, commonStore *persistent.CommonStore, consensus co
 					SandboxBinaryPath:     sandboxBinary,
 					InsecureNoSandbox:     insecureNoSandbox,
 					RuntimeAttestInterval: attestInterval,
+					Watchdog:              watchdogCfg,
 				})
 				if err != nil {
 					return nil, fmt.Errorf("failed to create SGX runtime provisioner: %w", err)
@@ -12,8 +12,9 @@ type checkTxQueue struct {
 
 	txs *deque.Deque[*PendingCheckTransaction]
 
-	maxSize      int
-	maxBatchSize int
+	maxSize       int
+	maxBatchSize  int
+	maxBatchBytes uint64
 }
 
 func (cq *checkTxQueue) add(pct *PendingCheckTransaction) error {
@@ -44,7 +45,10 @@ func (cq *checkTxQueue) pop() []*PendingCheckTransaction {
 	cq.l.Lock()
 	defer cq.l.Unlock()
 
-	var batch []*PendingCheckTransaction
+	var (
+		batch      []*PendingCheckTransaction
+		batchBytes uint64
+	)
 	for {
 		if cq.txs.Len() == 0 {
 			break
@@ -55,8 +59,16 @@ func (cq *checkTxQueue) pop() []*PendingCheckTransaction {
 			break
 		}
 
+		// Check if the batch already has enough bytes. A single transaction is always allowed
+		// so that large transactions can still be checked.
+		txBytes := uint64(len(cq.txs.Front().Raw()))
+		if cq.maxBatchBytes > 0 && len(batch) > 0 && batchBytes+txBytes > cq.maxBatchBytes {
+			break
+		}
+
 		tx := cq.txs.PopFront()
 		batch = append(batch, tx)
+		batchBytes += txBytes
 	}
 
 	return batch
@@ -76,10 +88,11 @@ func (cq *checkTxQueue) clear() {
 	cq.txs.Clear()
 }
 
-func newCheckTxQueue(maxSize, maxBatchSize int) *checkTxQueue {
+func newCheckTxQueue(maxSize, maxBatchSize int, maxBatchBytes uint64) *checkTxQueue {
 	return &checkTxQueue{
-		txs:          deque.New[*PendingCheckTransaction](0, 512),
-		maxSize:      maxSize,
-		maxBatchSize: maxBatchSize,
+		txs:           deque.New[*PendingCheckTransaction](0, 512),
+		maxSize:       maxSize,
+		maxBatchSize:  maxBatchSize,
+		maxBatchBytes: maxBatchBytes,
 	}
 }
@@ -19,7 +19,7 @@ func newPendingTx(tx []byte) *PendingCheckTransaction {
 }
 
 func TestCheckTxQueueBasic(t *testing.T) {
-	queue := newCheckTxQueue(51, 10)
+	queue := newCheckTxQueue(51, 10, 0)
 
 	err := queue.add(newPendingTx([]byte("hello world")))
 	require.NoError(t, err, "Add")
@@ -49,7 +49,7 @@ func TestCheckTxQueueBasic(t *testing.T) {
 }
 
 func TestCheckTxQueuePop(t *testing.T) {
-	queue := newCheckTxQueue(51, 10)
+	queue := newCheckTxQueue(51, 10, 0)
 
 	batch := queue.pop()
 	require.EqualValues(t, 0, len(batch), "Batch size")
@@ -62,3 +62,26 @@ func TestCheckTxQueuePop(t *testing.T) {
 	require.EqualValues(t, 1, len(batch), "Batch size")
 	require.EqualValues(t, 0, queue.size(), "Size")
 }
+
+func TestCheckTxQueueBatchBytes(t *testing.T) {
+	queue := newCheckTxQueue(51, 10, 10)
+
+	for _, tx := range []string{"aaaa", "bbbb", "cccc", "dddddddddddddddd", "e"} {
+		err := queue.add(newPendingTx([]byte(tx)))
+		require.NoError(t, err, "Add")
+	}
+
+	batch := queue.pop()
+	require.EqualValues(t, 2, len(batch), "Batch should be limited by bytes")
+
+	batch = queue.pop()
+	require.EqualValues(t, 1, len(batch), "Batch should be limited by bytes")
+
+	batch = queue.pop()
+	require.EqualValues(t, 1, len(batch), "Transactions larger than the limit should be popped alone")
+	require.EqualValues(t, []byte("dddddddddddddddd"), batch[0].Raw())
+
+	batch = queue.pop()
+	require.EqualValues(t, 1, len(batch), "Batch size")
+	require.EqualValues(t, 0, queue.size(), "Size")
+}
@@ -18,11 +18,14 @@ type localQueue struct {
 	l             sync.Mutex
 	txs           []*TxQueueMeta
 	indexesByHash map[hash.Hash]int
+	// notAfterByHash is the last schedulable round of txs that expire.
+	notAfterByHash map[hash.Hash]uint64
 }
 
 func newLocalQueue() *localQueue {
 	return &localQueue{
-		indexesByHash: map[hash.Hash]int{},
+		indexesByHash:  map[hash.Hash]int{},
+		notAfterByHash: map[hash.Hash]uint64{},
 	}
 }
 
@@ -45,11 +48,16 @@ func (lq *localQueue) GetTxByHash(h hash.Hash) *TxQueueMeta {
 func (lq *localQueue) HandleTxsUsed(hashes []hash.Hash) {
 	lq.l.Lock()
 	defer lq.l.Unlock()
+	lq.removeLocked(hashes)
+}
+
+func (lq *localQueue) removeLocked(hashes []hash.Hash) {
 	origCount := len(lq.txs)
 	keptCount := origCount
 	for _, h := range hashes {
 		if i, ok := lq.indexesByHash[h]; ok {
 			delete(lq.indexesByHash, h)
+			delete(lq.notAfterByHash, h)
 			lq.txs[i] = nil
 			keptCount--
 		}
@@ -75,17 +83,36 @@ func (lq *localQueue) TakeAll() []*TxQueueMeta {
 	txs := lq.txs
 	lq.txs = nil
 	lq.indexesByHash = make(map[hash.Hash]int)
+	lq.notAfterByHash = make(map[hash.Hash]uint64)
 	return txs
 }
 
-func (lq *localQueue) OfferChecked(tx *TxQueueMeta, _ *protocol.CheckTxMetadata) error {
+func (lq *localQueue) OfferChecked(tx *TxQueueMeta, meta *protocol.CheckTxMetadata) error {
 	lq.l.Lock()
 	defer lq.l.Unlock()
 	lq.indexesByHash[tx.Hash()] = len(lq.txs)
 	lq.txs = append(lq.txs, tx)
+	if meta != nil && meta.NotAfter != 0 {
+		lq.notAfterByHash[tx.Hash()] = meta.NotAfter
+	}
 	return nil
 }
 
+// EvictExpired removes all transactions that can no longer be scheduled after the given round and
+// returns the number of evicted transactions.
+func (lq *localQueue) EvictExpired(round uint64) int {
+	lq.l.Lock()
+	defer lq.l.Unlock()
+	var expired []hash.Hash
+	for h, notAfter := range lq.notAfterByHash {
+		if isExpired(notAfter, round) {
+			expired = append(expired, h)
+		}
+	}
+	lq.removeLocked(expired)
+	return len(expired)
+}
+
 func (lq *localQueue) GetTxsToPublish() []*TxQueueMeta {
 	lq.l.Lock()
 	defer lq.l.Unlock()
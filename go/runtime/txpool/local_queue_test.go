@@ -42,3 +42,26 @@ func TestLocalQueueBasic(t *testing.T) {
 	require.EqualValues(t, []*TxQueueMeta{txB}, lq.TakeAll(), "take all")
 	require.Len(t, lq.GetSchedulingSuggestion(50), 0, "after take all")
 }
+
+func TestLocalQueueEvictExpired(t *testing.T) {
+	lq := newLocalQueue()
+
+	rawA := []byte("a")
+	txA := &TxQueueMeta{raw: rawA, hash: hash.NewFromBytes(rawA)}
+	require.NoError(t, lq.OfferChecked(txA, &protocol.CheckTxMetadata{NotAfter: 10}), "offer checked a")
+	rawB := []byte("b")
+	txB := &TxQueueMeta{raw: rawB, hash: hash.NewFromBytes(rawB)}
+	require.NoError(t, lq.OfferChecked(txB, &protocol.CheckTxMetadata{}), "offer checked b")
+	rawC := []byte("c")
+	txC := &TxQueueMeta{raw: rawC, hash: hash.NewFromBytes(rawC)}
+	require.NoError(t, lq.OfferChecked(txC, &protocol.CheckTxMetadata{NotAfter: 20}), "offer checked c")
+
+	require.Equal(t, 0, lq.EvictExpired(9), "nothing should expire before the last round")
+	require.Equal(t, 1, lq.EvictExpired(10), "tx a should expire")
+	require.EqualValues(t, []*TxQueueMeta{txB, txC}, lq.GetSchedulingSuggestion(50), "after evict expired")
+	require.EqualValues(t, map[hash.Hash]int{txB.Hash(): 0, txC.Hash(): 1}, lq.indexesByHash, "after evict expired")
+
+	lq.HandleTxsUsed([]hash.Hash{txC.Hash()})
+	require.Equal(t, 0, lq.EvictExpired(100), "used txs should not be evicted")
+	require.EqualValues(t, []*TxQueueMeta{txB}, lq.TakeAll(), "take all")
+}
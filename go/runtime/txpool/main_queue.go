@@ -27,6 +27,10 @@ type MainQueueTransaction struct {
 	// senderStateSeq is the current (as of when the check was performed) sequence number of the
 	// sender stored in runtime state.
 	senderStateSeq uint64
+
+	// notAfter is the last round in which the transaction may be scheduled as specified by the
+	// runtime. Zero means no expiry.
+	notAfter uint64
 }
 
 func newTransaction(tx TxQueueMeta) *MainQueueTransaction {
@@ -55,6 +59,16 @@ func (tx *MainQueueTransaction) SenderSeq() uint64 {
 	return tx.senderSeq
 }
 
+// NotAfter returns the last round in which the transaction may be scheduled.
+func (tx *MainQueueTransaction) NotAfter() uint64 {
+	return tx.notAfter
+}
+
+// isExpired returns true if the transaction can no longer be scheduled after the given round.
+func (tx *MainQueueTransaction) isExpired(round uint64) bool {
+	return isExpired(tx.notAfter, round)
+}
+
 // setChecked populates transaction data retrieved from checks.
 func (tx *MainQueueTransaction) setChecked(meta *protocol.CheckTxMetadata) {
 	if meta != nil {
@@ -62,6 +76,7 @@ func (tx *MainQueueTransaction) setChecked(meta *protocol.CheckTxMetadata) {
 		tx.sender = string(meta.Sender)
 		tx.senderSeq = meta.SenderSeq
 		tx.senderStateSeq = meta.SenderStateSeq
+		tx.notAfter = meta.NotAfter
 	}
 
 	// If the sender is empty (e.g. because the runtime does not support specifying a sender), we
@@ -131,6 +146,12 @@ func (mq *mainQueue) OfferChecked(tx *TxQueueMeta, meta *protocol.CheckTxMetadat
 	return mq.inner.add(txMeta)
 }
 
+// EvictExpired removes all transactions that can no longer be scheduled after the given round and
+// returns the number of evicted transactions.
+func (mq *mainQueue) EvictExpired(round uint64) int {
+	return mq.inner.removeExpired(round)
+}
+
 func (mq *mainQueue) GetTxsToPublish() []*TxQueueMeta {
 	txMetas := mq.inner.getAll()
 	var txs []*TxQueueMeta
@@ -49,6 +49,13 @@ var (
 		},
 		[]string{"runtime"},
 	)
+	evictedTransactions = prometheus.NewCounterVec(
+		prometheus.CounterOpts{
+			Name: "oasis_txpool_evicted_transactions",
+			Help: "Number of transactions evicted from the transaction pool.",
+		},
+		[]string{"runtime", "reason"},
+	)
 	txpoolCollectors = []prometheus.Collector{
 		pendingCheckSize,
 		mainQueueSize,
@@ -56,6 +63,7 @@ var (
 		rimQueueSize,
 		rejectedTransactions,
 		acceptedTransactions,
+		evictedTransactions,
 	}
 
 	metricsOnce sync.Once
@@ -67,6 +75,13 @@ func (t *txPool) getMetricLabels() prometheus.Labels {
 	}
 }
 
+func (t *txPool) getEvictionMetricLabels(reason string) prometheus.Labels {
+	return prometheus.Labels{
+		"runtime": t.runtimeID.String(),
+		"reason":  reason,
+	}
+}
+
 func initMetrics() {
 	metricsOnce.Do(func() {
 		prometheus.MustRegister(txpoolCollectors...)
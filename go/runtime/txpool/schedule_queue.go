@@ -97,6 +97,22 @@ func (sq *scheduleQueue) remove(txHashes []hash.Hash) {
 	}
 }
 
+func (sq *scheduleQueue) removeExpired(round uint64) int {
+	sq.l.Lock()
+	defer sq.l.Unlock()
+
+	var expired []*MainQueueTransaction
+	for _, tx := range sq.all {
+		if tx.isExpired(round) {
+			expired = append(expired, tx)
+		}
+	}
+	for _, tx := range expired {
+		sq.removeLocked(tx)
+	}
+	return len(expired)
+}
+
 func (sq *scheduleQueue) getPrioritizedBatch(offset *hash.Hash, limit uint32) []*MainQueueTransaction {
 	sq.l.Lock()
 	defer sq.l.Unlock()
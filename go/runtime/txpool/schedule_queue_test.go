@@ -213,3 +213,28 @@ func TestScheduleQueueSender(t *testing.T) {
 	queue.remove([]hash.Hash{tx.Hash()})
 	require.Equal(0, queue.size())
 }
+
+func TestScheduleQueueRemoveExpired(t *testing.T) {
+	require := require.New(t)
+
+	queue := newScheduleQueue(10)
+
+	for i, notAfter := range []uint64{0, 5, 10, 15} {
+		tx := newTestTransaction([]byte(fmt.Sprintf("tx %d", i)), 0)
+		tx.notAfter = notAfter
+		require.NoError(queue.add(tx), "Add")
+	}
+
+	require.Equal(0, queue.removeExpired(4), "no transactions should expire before their last round")
+	require.Equal(4, queue.size())
+
+	require.Equal(2, queue.removeExpired(10), "transactions should expire after their last round")
+	require.Equal(2, queue.size())
+
+	for _, tx := range queue.getAll() {
+		require.False(tx.isExpired(10), "remaining transactions should not be expired")
+	}
+
+	require.Equal(1, queue.removeExpired(100), "Remove expired")
+	require.Equal(1, queue.size(), "transactions without a last round should never expire")
+}
@@ -16,6 +16,12 @@ func (f txCheckFlags) isRecheck() bool {
 	return (f * txCheckRecheck) != 0
 }
 
+// isExpired returns true if a transaction with the given last schedulable round can no longer be
+// scheduled after the given round.
+func isExpired(notAfter, round uint64) bool {
+	return notAfter != 0 && notAfter <= round
+}
+
 // PendingCheckTransaction is a transaction pending checks.
 type PendingCheckTransaction struct {
 	*TxQueueMeta
@@ -26,4 +32,6 @@ type PendingCheckTransaction struct {
 	dstQueue RecheckableTransactionStore
 	// notifyCh is a channel for sending back the transaction check result.
 	notifyCh chan *protocol.CheckTxResult
+	// checkFailures is the number of times checking a batch containing the transaction failed.
+	checkFailures int
 }
@@ -12,6 +12,7 @@ import (
 	"github.com/oasisprotocol/oasis-core/go/common"
 	"github.com/oasisprotocol/oasis-core/go/common/cache/lru"
 	"github.com/oasisprotocol/oasis-core/go/common/crypto/hash"
+	cmnErrors "github.com/oasisprotocol/oasis-core/go/common/errors"
 	"github.com/oasisprotocol/oasis-core/go/common/logging"
 	"github.com/oasisprotocol/oasis-core/go/common/pubsub"
 	"github.com/oasisprotocol/oasis-core/go/roothash/api/block"
@@ -28,6 +29,9 @@ const (
 	checkTxTimeout = 15 * time.Second
 	// checkTxRetryDelay is the time to wait before queuing a check tx retry.
 	checkTxRetryDelay = 1 * time.Second
+	// maxCheckTxFailures is the number of failed batch checks after which a transaction is
+	// evicted instead of being retried.
+	maxCheckTxFailures = 10
 	// abortTimeout is the maximum time the runtime can spend aborting.
 	abortTimeout = 5 * time.Second
 	// maxRepublishTxs is the maximum amount of transactions to republish.
@@ -40,8 +44,40 @@ const (
 	// case when the maxRepublishTxs limit is reached. This should be much shorter than the
 	// RepublishInterval.
 	republishLimitReinvokeTimeout = 1 * time.Second
+
+	// evictionReasonExpired is the eviction reason for transactions past their last schedulable
+	// round.
+	evictionReasonExpired = "expired"
+	// evictionReasonRecheckFailed is the eviction reason for transactions failing a recheck.
+	evictionReasonRecheckFailed = "recheck_failed"
+	// evictionReasonCheckFailed is the eviction reason for transactions whose batch check
+	// repeatedly failed.
+	evictionReasonCheckFailed = "check_failed"
+)
+
+// moduleName is the module name used for transaction pool errors.
+const moduleName = "txpool"
+
+// Code 1 is used for transactions that could not be queued after passing checks.
+var (
+	// ErrTxExpired is the error returned when a transaction can no longer be scheduled.
+	ErrTxExpired = cmnErrors.New(moduleName, 2, "txpool: transaction expired")
+	// ErrTxCheckFailed is the error returned when checking a transaction repeatedly failed.
+	ErrTxCheckFailed = cmnErrors.New(moduleName, 3, "txpool: transaction check repeatedly failed")
 )
 
+// errorToCheckTxResult converts the given error into a failed check transaction result.
+func errorToCheckTxResult(err error) protocol.CheckTxResult {
+	module, code := cmnErrors.Code(err)
+	return protocol.CheckTxResult{
+		Error: protocol.Error{
+			Module:  module,
+			Code:    code,
+			Message: err.Error(),
+		},
+	}
+}
+
 // TransactionMeta contains the per-transaction metadata.
 type TransactionMeta struct {
 	// Local is a flag indicating that the transaction was obtained from a local client.
@@ -392,6 +428,21 @@ func (t *txPool) ProcessBlock(bi *runtime.BlockInfo) {
 		t.recheckTxCh.In() <- struct{}{}
 		t.lastRecheckRound = bi.RuntimeBlock.Header.Round
 	}
+
+	// Evict transactions that can no longer be scheduled.
+	round := bi.RuntimeBlock.Header.Round
+	numLocal := t.localQueue.EvictExpired(round)
+	numMain := t.mainQueue.EvictExpired(round)
+	if n := numLocal + numMain; n > 0 {
+		t.logger.Debug("evicted expired transactions",
+			"num_local_txs", numLocal,
+			"num_main_txs", numMain,
+			"round", round,
+		)
+		evictedTransactions.With(t.getEvictionMetricLabels(evictionReasonExpired)).Add(float64(n))
+		localQueueSize.With(t.getMetricLabels()).Set(float64(t.localQueue.size()))
+		mainQueueSize.With(t.getMetricLabels()).Set(float64(t.mainQueue.inner.size()))
+	}
 }
 
 func (t *txPool) ProcessIncomingMessages(inMsgs []*message.IncomingMessage) {
@@ -475,8 +526,29 @@ func (t *txPool) checkTxBatch(ctx context.Context, rr host.RichRuntime) {
 			"err", err,
 		)
 
-		// Return transaction batch back to the check queue.
-		t.checkTxQueue.retryBatch(batch)
+		// Evict transactions that repeatedly failed to be checked and return the rest of the
+		// transaction batch back to the check queue.
+		retry := make([]*PendingCheckTransaction, 0, len(batch))
+		for _, pct := range batch {
+			pct.checkFailures++
+			if pct.checkFailures < maxCheckTxFailures {
+				retry = append(retry, pct)
+				continue
+			}
+
+			t.logger.Warn("evicting transaction that repeatedly failed to be checked",
+				"tx_hash", pct.Hash(),
+				"failures", pct.checkFailures,
+			)
+			evictedTransactions.With(t.getEvictionMetricLabels(evictionReasonCheckFailed)).Inc()
+			if pct.notifyCh != nil {
+				result := errorToCheckTxResult(ErrTxCheckFailed)
+				pct.notifyCh <- &result
+				close(pct.notifyCh)
+				pct.notifyCh = nil
+			}
+		}
+		t.checkTxQueue.retryBatch(retry)
 
 		// Make sure that the batch check is retried later.
 		go func() {
@@ -501,8 +573,22 @@ func (t *txPool) checkTxBatch(ctx context.Context, rr host.RichRuntime) {
 	goodPcts := make([]*PendingCheckTransaction, 0, len(results))
 	batchIndices := make([]int, 0, len(results))
 	for i, res := range results {
+		// Reject transactions that could no longer be scheduled.
+		expired := res.IsSuccess() && res.Meta != nil && isExpired(res.Meta.NotAfter, bi.RuntimeBlock.Header.Round)
+		if expired {
+			results[i] = errorToCheckTxResult(ErrTxExpired)
+			res = results[i]
+		}
+
 		if !res.IsSuccess() {
 			rejectedTransactions.With(t.getMetricLabels()).Inc()
+			if batch[i].flags.isRecheck() {
+				reason := evictionReasonRecheckFailed
+				if expired {
+					reason = evictionReasonExpired
+				}
+				evictedTransactions.With(t.getEvictionMetricLabels(reason)).Inc()
+			}
 			t.logger.Debug("check tx failed",
 				"tx", batch[i].Raw(),
 				"tx_hash", batch[i].Hash(),
@@ -862,7 +948,7 @@ func New(
 		history:              history,
 		txPublisher:          txPublisher,
 		seenCache:            seenCache,
-		checkTxQueue:         newCheckTxQueue(maxCheckTxQueueSize, int(cfg.MaxCheckTxBatchSize)),
+		checkTxQueue:         newCheckTxQueue(maxCheckTxQueueSize, int(cfg.MaxCheckTxBatchSize), cfg.MaxCheckTxBatchBytes),
 		checkTxCh:            channels.NewRingChannel(1),
 		checkTxNotifier:      pubsub.NewBroker(false),
 		recheckTxCh:          channels.NewRingChannel(1),
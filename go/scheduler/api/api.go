@@ -363,6 +363,20 @@ type Backend interface {
 	// be sent immediately.
 	WatchCommittees(ctx context.Context) (<-chan *Committee, pubsub.ClosableSubscription, error)
 
+	// WatchNodeCommittees returns a channel that produces a stream of
+	// Committee, limited to committees that the given node is a member of.
+	//
+	// Upon subscription, all such committees for the current epoch will
+	// be sent immediately.
+	WatchNodeCommittees(ctx context.Context, nodeID signature.PublicKey) (<-chan *Committee, pubsub.ClosableSubscription, error)
+
+	// WatchValidators returns a channel that produces a stream of
+	// consensus validator sets, one for each validator election.
+	//
+	// Upon subscription, the current validator set will be sent
+	// immediately.
+	WatchValidators(ctx context.Context) (<-chan []*Validator, pubsub.ClosableSubscription, error)
+
 	// StateToGenesis returns the genesis state at specified block height.
 	StateToGenesis(ctx context.Context, height int64) (*Genesis, error)
 
@@ -5,6 +5,7 @@ import (
 
 	"google.golang.org/grpc"
 
+	"github.com/oasisprotocol/oasis-core/go/common/crypto/signature"
 	cmnGrpc "github.com/oasisprotocol/oasis-core/go/common/grpc"
 	"github.com/oasisprotocol/oasis-core/go/common/pubsub"
 )
@@ -24,6 +25,10 @@ var (
 
 	// methodWatchCommittees is the WatchCommittees method.
 	methodWatchCommittees = serviceName.NewMethod("WatchCommittees", nil)
+	// methodWatchNodeCommittees is the WatchNodeCommittees method.
+	methodWatchNodeCommittees = serviceName.NewMethod("WatchNodeCommittees", signature.PublicKey{})
+	// methodWatchValidators is the WatchValidators method.
+	methodWatchValidators = serviceName.NewMethod("WatchValidators", nil)
 
 	// serviceDesc is the gRPC service descriptor.
 	serviceDesc = grpc.ServiceDesc{
@@ -53,6 +58,16 @@ var (
 				Handler:       handlerWatchCommittees,
 				ServerStreams: true,
 			},
+			{
+				StreamName:    methodWatchNodeCommittees.ShortName(),
+				Handler:       handlerWatchNodeCommittees,
+				ServerStreams: true,
+			},
+			{
+				StreamName:    methodWatchValidators.ShortName(),
+				Handler:       handlerWatchValidators,
+				ServerStreams: true,
+			},
 		},
 	}
 )
@@ -177,6 +192,63 @@ func handlerWatchCommittees(srv interface{}, stream grpc.ServerStream) error {
 	}
 }
 
+func handlerWatchNodeCommittees(srv interface{}, stream grpc.ServerStream) error {
+	var nodeID signature.PublicKey
+	if err := stream.RecvMsg(&nodeID); err != nil {
+		return err
+	}
+
+	ctx := stream.Context()
+	ch, sub, err := srv.(Backend).WatchNodeCommittees(ctx, nodeID)
+	if err != nil {
+		return err
+	}
+	defer sub.Close()
+
+	for {
+		select {
+		case c, ok := <-ch:
+			if !ok {
+				return nil
+			}
+
+			if err := stream.SendMsg(c); err != nil {
+				return err
+			}
+		case <-ctx.Done():
+			return ctx.Err()
+		}
+	}
+}
+
+func handlerWatchValidators(srv interface{}, stream grpc.ServerStream) error {
+	if err := stream.RecvMsg(nil); err != nil {
+		return err
+	}
+
+	ctx := stream.Context()
+	ch, sub, err := srv.(Backend).WatchValidators(ctx)
+	if err != nil {
+		return err
+	}
+	defer sub.Close()
+
+	for {
+		select {
+		case vs, ok := <-ch:
+			if !ok {
+				return nil
+			}
+
+			if err := stream.SendMsg(vs); err != nil {
+				return err
+			}
+		case <-ctx.Done():
+			return ctx.Err()
+		}
+	}
+}
+
 // RegisterService registers a new scheduler service with the given gRPC server.
 func RegisterService(server *grpc.Server, service Backend) {
 	server.RegisterService(&serviceDesc, service)
@@ -253,6 +325,76 @@ func (c *schedulerClient) WatchCommittees(ctx context.Context) (<-chan *Committe
 	return ch, sub, nil
 }
 
+func (c *schedulerClient) WatchNodeCommittees(ctx context.Context, nodeID signature.PublicKey) (<-chan *Committee, pubsub.ClosableSubscription, error) {
+	ctx, sub := pubsub.NewContextSubscription(ctx)
+
+	stream, err := c.conn.NewStream(ctx, &serviceDesc.Streams[1], methodWatchNodeCommittees.FullName())
+	if err != nil {
+		return nil, nil, err
+	}
+	if err = stream.SendMsg(nodeID); err != nil {
+		return nil, nil, err
+	}
+	if err = stream.CloseSend(); err != nil {
+		return nil, nil, err
+	}
+
+	ch := make(chan *Committee)
+	go func() {
+		defer close(ch)
+
+		for {
+			var ev Committee
+			if serr := stream.RecvMsg(&ev); serr != nil {
+				return
+			}
+
+			select {
+			case ch <- &ev:
+			case <-ctx.Done():
+				return
+			}
+		}
+	}()
+
+	return ch, sub, nil
+}
+
+func (c *schedulerClient) WatchValidators(ctx context.Context) (<-chan []*Validator, pubsub.ClosableSubscription, error) {
+	ctx, sub := pubsub.NewContextSubscription(ctx)
+
+	stream, err := c.conn.NewStream(ctx, &serviceDesc.Streams[2], methodWatchValidators.FullName())
+	if err != nil {
+		return nil, nil, err
+	}
+	if err = stream.SendMsg(nil); err != nil {
+		return nil, nil, err
+	}
+	if err = stream.CloseSend(); err != nil {
+		return nil, nil, err
+	}
+
+	ch := make(chan []*Validator)
+	go func() {
+		defer close(ch)
+
+		for {
+			var vs []*Validator
+			if serr := stream.RecvMsg(&vs); serr != nil {
+				return
+			}
+
+			select {
+			case ch <- vs:
+			case <-ctx.Done():
+				return
+			}
+		}
+	}()
+
+	return ch, sub, nil
+}
+
 func (c *schedulerClient) Cleanup() {
 }
 
@@ -44,10 +44,42 @@ func SchedulerImplementationTests(t *testing.T, name string, identity *identity.
 	require.NoError(err, "WatchCommittees")
 	defer sub.Close()
 
+	var workerID signature.PublicKey
+	for _, n := range nodes {
+		if n.HasRoles(node.RoleComputeWorker) {
+			workerID = n.ID
+			break
+		}
+	}
+	nodeCh, nodeSub, err := backend.WatchNodeCommittees(ctx, workerID)
+	require.NoError(err, "WatchNodeCommittees")
+	defer nodeSub.Close()
+
+	valCh, valSub, err := backend.WatchValidators(ctx)
+	require.NoError(err, "WatchValidators")
+	defer valSub.Close()
+
+	// Drain the current validator set sent on subscription.
+	select {
+	case <-valCh:
+	case <-time.After(recvTimeout):
+		t.Fatalf("failed to receive current validators")
+	}
+
 	// Advance the epoch.
 	timeSource := consensus.Beacon().(beacon.SetableBackend)
 	epoch := beaconTests.MustAdvanceEpoch(t, timeSource)
 
+	// Validators should be elected at the epoch transition.
+	select {
+	case watched := <-valCh:
+		require.Len(watched, 1, "should be only one watched validator")
+		require.Equal(identity.NodeSigner.Public(), watched[0].ID)
+		require.EqualValues(1, watched[0].VotingPower)
+	case <-time.After(recvTimeout):
+		t.Fatalf("failed to receive validator update")
+	}
+
 	ensureValidCommittees := func(expectedExecutor int) {
 		var executor *api.Committee
 		var seen int
@@ -105,6 +137,20 @@ func SchedulerImplementationTests(t *testing.T, name string, identity *identity.
 		nExecutor,
 	)
 
+	// All compute workers should be members of the executor committee.
+	for {
+		var committee *api.Committee
+		select {
+		case committee = <-nodeCh:
+		case <-time.After(recvTimeout):
+			t.Fatalf("failed to receive node committee event")
+		}
+		require.True(committee.IsMember(workerID), "node should be a committee member")
+		if committee.ValidFor == epoch && rt.Runtime.ID.Equal(&committee.RuntimeID) {
+			break
+		}
+	}
+
 	// Re-register the runtime with less nodes.
 	rt.Runtime.Executor.GroupSize = 2
 	rt.Runtime.Executor.GroupBackupSize = 1
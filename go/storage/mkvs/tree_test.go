@@ -2392,6 +2392,34 @@ func benchmarkInsertBatch(b *testing.B, numValues int, commit bool) {
 	}
 }
 
+func BenchmarkGet1000(b *testing.B) {
+	benchmarkGet(b, 1000)
+}
+
+func BenchmarkGet10000(b *testing.B) {
+	benchmarkGet(b, 10000)
+}
+
+func benchmarkGet(b *testing.B, numValues int) {
+	ctx := context.Background()
+
+	tree := New(nil, nil, node.RootTypeState)
+	defer tree.Close()
+
+	keys, values := generateKeyValuePairsEx("", numValues)
+	for i := range keys {
+		err := tree.Insert(ctx, keys[i], values[i])
+		require.NoError(b, err, "Insert")
+	}
+	_, _, err := tree.Commit(ctx, testNs, 0)
+	require.NoError(b, err, "Commit")
+
+	b.ResetTimer()
+	for n := 0; n < b.N; n++ {
+		_, _ = tree.Get(ctx, keys[n%numValues])
+	}
+}
+
 func generateKeyValuePairsEx(prefix string, count int) ([][]byte, [][]byte) {
 	keys := make([][]byte, count)
 	values := make([][]byte, count)
@@ -0,0 +1,66 @@
+package committee
+
+import (
+	"github.com/oasisprotocol/oasis-core/go/common/cache/lru"
+	"github.com/oasisprotocol/oasis-core/go/common/crypto/hash"
+)
+
+// queryCacheKey is the key under which query responses are cached.
+type queryCacheKey struct {
+	Round  uint64
+	Method string
+	Args   []byte
+}
+
+// queryCacheValue is a cached query response.
+type queryCacheValue []byte
+
+// Size implements lru.Sizeable.
+func (v queryCacheValue) Size() uint64 {
+	return uint64(len(v))
+}
+
+// queryCache is a byte-size bounded cache of runtime query responses.
+//
+// Responses are cached under the resolved round so that queries against the latest round are
+// automatically invalidated once a new block is finalized.
+type queryCache struct {
+	cache *lru.Cache
+}
+
+func (qc *queryCache) key(round uint64, method string, args []byte) hash.Hash {
+	return hash.NewFrom(&queryCacheKey{
+		Round:  round,
+		Method: method,
+		Args:   args,
+	})
+}
+
+// get returns a copy of the cached response for the given query, if any.
+func (qc *queryCache) get(round uint64, method string, args []byte) ([]byte, bool) {
+	data, ok := qc.cache.Get(qc.key(round, method, args))
+	if !ok {
+		return nil, false
+	}
+	return append([]byte{}, data.(queryCacheValue)...), true
+}
+
+// put caches a copy of the response for the given query.
+func (qc *queryCache) put(round uint64, method string, args []byte, data []byte) {
+	// Responses larger than the cache are not cached.
+	_ = qc.cache.Put(qc.key(round, method, args), queryCacheValue(append([]byte{}, data...)))
+}
+
+// clear removes all cached responses.
+func (qc *queryCache) clear() {
+	qc.cache.Clear()
+}
+
+// newQueryCache creates a new query cache holding at most size bytes of responses.
+func newQueryCache(size uint64) (*queryCache, error) {
+	cache, err := lru.New(lru.Capacity(size, true))
+	if err != nil {
+		return nil, err
+	}
+	return &queryCache{cache: cache}, nil
+}
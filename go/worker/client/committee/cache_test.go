@@ -0,0 +1,68 @@
+package committee
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/require"
+
+	"github.com/oasisprotocol/oasis-core/go/runtime/host"
+)
+
+func TestQueryCache(t *testing.T) {
+	require := require.New(t)
+
+	qc, err := newQueryCache(16)
+	require.NoError(err, "newQueryCache")
+
+	// Cache hits.
+	_, ok := qc.get(1, "method", []byte("args"))
+	require.False(ok, "empty cache should miss")
+
+	rsp := []byte("response")
+	qc.put(1, "method", []byte("args"), rsp)
+	data, ok := qc.get(1, "method", []byte("args"))
+	require.True(ok, "cached response should hit")
+	require.Equal(rsp, data)
+
+	// Cached responses should not be affected by modifications of returned or stored slices.
+	data[0] = 'x'
+	rsp[1] = 'x'
+	data, ok = qc.get(1, "method", []byte("args"))
+	require.True(ok)
+	require.Equal([]byte("response"), data, "cached response should be copied")
+
+	_, ok = qc.get(1, "method", []byte("other"))
+	require.False(ok, "queries with different arguments should miss")
+	_, ok = qc.get(1, "other", []byte("args"))
+	require.False(ok, "queries with different methods should miss")
+
+	// Round invalidation.
+	_, ok = qc.get(2, "method", []byte("args"))
+	require.False(ok, "queries against a new round should miss")
+
+	// Byte-sized capacity.
+	qc.put(2, "method", []byte("args"), []byte("response"))
+	qc.put(3, "method", []byte("args"), []byte("response"))
+	_, ok = qc.get(1, "method", []byte("args"))
+	require.False(ok, "least recently used response should be evicted")
+	qc.put(4, "method", []byte("args"), make([]byte, 17))
+	_, ok = qc.get(4, "method", []byte("args"))
+	require.False(ok, "responses larger than the cache should not be cached")
+}
+
+func TestQueryCacheClearOnStarted(t *testing.T) {
+	require := require.New(t)
+
+	qc, err := newQueryCache(1024)
+	require.NoError(err, "newQueryCache")
+	n := &Node{queryCache: qc}
+
+	qc.put(1, "method", nil, []byte("response"))
+	n.HandleRuntimeHostEventLocked(&host.Event{Updated: &host.UpdatedEvent{}})
+	_, ok := qc.get(1, "method", nil)
+	require.True(ok, "cache should not be cleared on update events")
+
+	n.HandleRuntimeHostEventLocked(&host.Event{Started: &host.StartedEvent{}})
+	_, ok = qc.get(1, "method", nil)
+	require.False(ok, "cache should be cleared when the runtime is restarted")
+}
@@ -10,8 +10,11 @@ import (
 	"github.com/eapache/channels"
 
 	cmnBackoff "github.com/oasisprotocol/oasis-core/go/common/backoff"
+	"github.com/oasisprotocol/oasis-core/go/common/cbor"
 	"github.com/oasisprotocol/oasis-core/go/common/crypto/hash"
+	"github.com/oasisprotocol/oasis-core/go/common/errors"
 	"github.com/oasisprotocol/oasis-core/go/common/logging"
+	"github.com/oasisprotocol/oasis-core/go/config"
 	"github.com/oasisprotocol/oasis-core/go/roothash/api/block"
 	runtime "github.com/oasisprotocol/oasis-core/go/runtime/api"
 	"github.com/oasisprotocol/oasis-core/go/runtime/client/api"
@@ -39,6 +42,8 @@ type Node struct {
 
 	txCh *channels.InfiniteChannel
 
+	queryCache *queryCache
+
 	logger *logging.Logger
 }
 
@@ -84,11 +89,43 @@ func (n *Node) HandleNewBlockLocked(*runtime.BlockInfo) {
 }
 
 // HandleRuntimeHostEventLocked is guarded by CrossNode.
-func (n *Node) HandleRuntimeHostEventLocked(*host.Event) {
-	// Nothing to do here.
+func (n *Node) HandleRuntimeHostEventLocked(ev *host.Event) {
+	// Cached query responses may have been produced by a different runtime version.
+	if n.queryCache != nil && ev.Started != nil {
+		n.queryCache.clear()
+	}
+}
+
+// validateTx performs stateless checks of a submitted transaction so that malformed input
+// is rejected before being queued for checks by the runtime.
+func (n *Node) validateTx(tx []byte) error {
+	cfg := config.GlobalConfig.Runtime.TxValidation
+
+	maxTxSize := cfg.MaxTxSize
+	n.commonNode.CrossNode.Lock()
+	if dsc := n.commonNode.CurrentDescriptor; dsc != nil {
+		if maxBatchSize := dsc.TxnScheduler.MaxBatchSizeBytes; maxBatchSize > 0 && (maxTxSize == 0 || maxBatchSize < maxTxSize) {
+			maxTxSize = maxBatchSize
+		}
+	}
+	n.commonNode.CrossNode.Unlock()
+
+	if maxTxSize > 0 && uint64(len(tx)) > maxTxSize {
+		return errors.WithContext(api.ErrTxTooLarge, fmt.Sprintf("size %d exceeds limit %d", len(tx), maxTxSize))
+	}
+	if cfg.StrictCBOR {
+		if err := cbor.Valid(tx); err != nil {
+			return errors.WithContext(api.ErrMalformedTx, err.Error())
+		}
+	}
+	return nil
 }
 
 func (n *Node) SubmitTx(ctx context.Context, tx []byte) (<-chan *api.SubmitTxResult, *protocol.Error, error) {
+	if err := n.validateTx(tx); err != nil {
+		return nil, nil, err
+	}
+
 	// Make sure consensus is synced.
 	select {
 	case <-n.commonNode.Consensus.Synced():
@@ -117,6 +154,10 @@ func (n *Node) SubmitTx(ctx context.Context, tx []byte) (<-chan *api.SubmitTxRes
 }
 
 func (n *Node) CheckTx(ctx context.Context, tx []byte) (*protocol.CheckTxResult, error) {
+	if err := n.validateTx(tx); err != nil {
+		return nil, err
+	}
+
 	return n.commonNode.TxPool.SubmitTx(ctx, tx, &txpool.TransactionMeta{Local: true, Discard: true})
 }
 
@@ -142,6 +183,12 @@ func (n *Node) Query(ctx context.Context, round uint64, method string, args []by
 		return nil, fmt.Errorf("client: failed to fetch annotated block from history: %w", err)
 	}
 
+	if n.queryCache != nil {
+		if data, ok := n.queryCache.get(annBlk.Block.Header.Round, method, args); ok {
+			return data, nil
+		}
+	}
+
 	lb, err := n.commonNode.Consensus.GetLightBlock(ctx, annBlk.Height)
 	if err != nil {
 		return nil, fmt.Errorf("client: failed to get light block at height %d: %w", annBlk.Height, err)
@@ -151,7 +198,14 @@ func (n *Node) Query(ctx context.Context, round uint64, method string, args []by
 		return nil, fmt.Errorf("client: failed to get epoch at height %d: %w", annBlk.Height, err)
 	}
 
-	return hrt.Query(ctx, annBlk.Block, lb, epoch, maxMessages, method, args)
+	data, err := hrt.Query(ctx, annBlk.Block, lb, epoch, maxMessages, method, args)
+	if err != nil {
+		return nil, err
+	}
+	if n.queryCache != nil {
+		n.queryCache.put(annBlk.Block.Header.Round, method, args, data)
+	}
+	return data, nil
 }
 
 func (n *Node) checkBlock(ctx context.Context, blk *block.Block, pending map[hash.Hash]*pendingTx) error {
@@ -300,5 +354,14 @@ func NewNode(commonNode *committee.Node) (*Node, error) {
 		txCh:       channels.NewInfiniteChannel(),
 		logger:     logging.GetLogger("worker/client/committee").With("runtime_id", commonNode.Runtime.ID()),
 	}
+
+	if size := config.GlobalConfig.Runtime.QueryCacheSize; size > 0 {
+		queryCache, err := newQueryCache(size)
+		if err != nil {
+			return nil, fmt.Errorf("failed to create query cache: %w", err)
+		}
+		n.queryCache = queryCache
+	}
+
 	return n, nil
 }
@@ -52,5 +52,5 @@ func (s *service) handleGetTxs(request *GetTxsRequest) (*GetTxsResponse, error)
 
 // NewServer creates a new transaction sync protocol server.
 func NewServer(chainContext string, runtimeID common.Namespace, txPool txpool.TransactionPool) rpc.Server {
-	return rpc.NewServer(protocol.NewRuntimeProtocolID(chainContext, runtimeID, TxSyncProtocolID, TxSyncProtocolVersion), &service{txPool})
+	return rpc.NewServer(protocol.NewRuntimeProtocolID(chainContext, runtimeID, TxSyncProtocolID, TxSyncProtocolVersion), &service{txPool}, rpc.ServerOptionsFromConfig()...)
 }
@@ -1,6 +1,8 @@
 // Package config implements global configuration options.
 package config
 
+import "fmt"
+
 // Config is the sentry worker configuration structure.
 type Config struct {
 	// Enable Sentry worker.
@@ -17,10 +19,42 @@ type ControlConfig struct {
 
 	// Public keys of upstream nodes that are allowed to connect to sentry control endpoint.
 	AuthorizedPubkeys []string `yaml:"authorized_pubkeys"`
+
+	// Rate limiting and admission control of the sentry worker's gRPC server.
+	Limits LimitsConfig `yaml:"limits,omitempty"`
+}
+
+// LimitsConfig is the sentry worker gRPC server limits configuration structure.
+//
+// Zero values disable the corresponding limit.
+type LimitsConfig struct {
+	// Number of requests per second a single peer can make.
+	PeerRate float64 `yaml:"peer_rate,omitempty"`
+	// Number of requests a single peer can make in a burst.
+	PeerBurst int `yaml:"peer_burst,omitempty"`
+	// Number of requests per second all peers together can make.
+	GlobalRate float64 `yaml:"global_rate,omitempty"`
+	// Number of requests all peers together can make in a burst.
+	GlobalBurst int `yaml:"global_burst,omitempty"`
+	// Maximum number of requests handled concurrently.
+	MaxConcurrentRequests int `yaml:"max_concurrent_requests,omitempty"`
+	// Maximum number of concurrent streams per connection.
+	MaxConcurrentStreams uint32 `yaml:"max_concurrent_streams,omitempty"`
 }
 
 // Validate validates the configuration settings.
 func (c *Config) Validate() error {
+	limits := c.Control.Limits
+	if limits.PeerRate < 0 || limits.PeerBurst < 0 {
+		return fmt.Errorf("control.limits.peer_rate and peer_burst must be >= 0")
+	}
+	if limits.GlobalRate < 0 || limits.GlobalBurst < 0 {
+		return fmt.Errorf("control.limits.global_rate and global_burst must be >= 0")
+	}
+	if limits.MaxConcurrentRequests < 0 {
+		return fmt.Errorf("control.limits.max_concurrent_requests must be >= 0")
+	}
+
 	return nil
 }
 
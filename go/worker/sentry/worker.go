@@ -92,6 +92,18 @@ func (w *Worker) Cleanup() {
 	w.grpcServer.Cleanup()
 }
 
+func limitsFromConfig() *grpc.LimitsConfig {
+	cfg := config.GlobalConfig.Sentry.Control.Limits
+	return &grpc.LimitsConfig{
+		PeerRate:              cfg.PeerRate,
+		PeerBurst:             cfg.PeerBurst,
+		GlobalRate:            cfg.GlobalRate,
+		GlobalBurst:           cfg.GlobalBurst,
+		MaxConcurrentRequests: cfg.MaxConcurrentRequests,
+		MaxConcurrentStreams:  cfg.MaxConcurrentStreams,
+	}
+}
+
 // New creates a new sentry worker.
 func New(backend api.Backend, identity *identity.Identity) (*Worker, error) {
 	w := &Worker{
@@ -115,6 +127,7 @@ func New(backend api.Backend, identity *identity.Identity) (*Worker, error) {
 			Port:     config.GlobalConfig.Sentry.Control.Port,
 			Identity: identity,
 			AuthFunc: peerPubkeyAuth.AuthFunc,
+			Limits:   limitsFromConfig(),
 		})
 		if err != nil {
 			return nil, fmt.Errorf("worker/sentry: failed to create a new gRPC server: %w", err)
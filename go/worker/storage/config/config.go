@@ -22,6 +22,9 @@ type Config struct {
 
 	// Storage checkpointer configuration.
 	Checkpointer CheckpointerConfig `yaml:"checkpointer,omitempty"`
+
+	// Storage public RPC client configuration.
+	PublicRPCClient PublicRPCClientConfig `yaml:"public_rpc_client,omitempty"`
 }
 
 // CheckpointerConfig is the storage worker checkpointer configuration structure.
@@ -32,11 +35,32 @@ type CheckpointerConfig struct {
 	CheckInterval time.Duration `yaml:"check_interval"`
 }
 
+// PublicRPCClientConfig is the storage public RPC client configuration structure.
+//
+// Zero values use the protocol defaults.
+type PublicRPCClientConfig struct {
+	// Maximum time a single peer may take to respond to a Get request.
+	MaxGetResponseTime time.Duration `yaml:"max_get_response_time,omitempty"`
+	// Maximum time a single peer may take to respond to a GetPrefixes request.
+	MaxGetPrefixesResponseTime time.Duration `yaml:"max_get_prefixes_response_time,omitempty"`
+	// Maximum time a single peer may take to respond to an Iterate request.
+	MaxIterateResponseTime time.Duration `yaml:"max_iterate_response_time,omitempty"`
+}
+
 // Validate validates the configuration settings.
 func (c *Config) Validate() error {
 	if c.Backend != "badger" {
 		return fmt.Errorf("unknown storage backend: %s", c.Backend)
 	}
+	if c.PublicRPCClient.MaxGetResponseTime < 0 {
+		return fmt.Errorf("public_rpc_client.max_get_response_time must not be negative")
+	}
+	if c.PublicRPCClient.MaxGetPrefixesResponseTime < 0 {
+		return fmt.Errorf("public_rpc_client.max_get_prefixes_response_time must not be negative")
+	}
+	if c.PublicRPCClient.MaxIterateResponseTime < 0 {
+		return fmt.Errorf("public_rpc_client.max_iterate_response_time must not be negative")
+	}
 
 	return nil
 }
@@ -2,8 +2,10 @@ package pub
 
 import (
 	"context"
+	"time"
 
 	"github.com/oasisprotocol/oasis-core/go/common"
+	"github.com/oasisprotocol/oasis-core/go/config"
 	"github.com/oasisprotocol/oasis-core/go/p2p/protocol"
 	"github.com/oasisprotocol/oasis-core/go/p2p/rpc"
 )
@@ -33,11 +35,17 @@ type Client interface {
 type client struct {
 	rc  rpc.Client
 	mgr rpc.PeerManager
+
+	maxGetResponseTime         time.Duration
+	maxGetPrefixesResponseTime time.Duration
+	maxIterateResponseTime     time.Duration
 }
 
 func (c *client) Get(ctx context.Context, request *GetRequest) (*ProofResponse, rpc.PeerFeedback, error) {
 	var rsp ProofResponse
-	pf, err := c.rc.CallOne(ctx, c.mgr.GetBestPeers(), MethodGet, request, &rsp)
+	pf, err := c.rc.CallOne(ctx, c.mgr.GetBestPeers(), MethodGet, request, &rsp,
+		rpc.WithMaxPeerResponseTime(c.maxGetResponseTime),
+	)
 	if err != nil {
 		return nil, nil, err
 	}
@@ -46,7 +54,9 @@ func (c *client) Get(ctx context.Context, request *GetRequest) (*ProofResponse,
 
 func (c *client) GetPrefixes(ctx context.Context, request *GetPrefixesRequest) (*ProofResponse, rpc.PeerFeedback, error) {
 	var rsp ProofResponse
-	pf, err := c.rc.CallOne(ctx, c.mgr.GetBestPeers(), MethodGetPrefixes, request, &rsp)
+	pf, err := c.rc.CallOne(ctx, c.mgr.GetBestPeers(), MethodGetPrefixes, request, &rsp,
+		rpc.WithMaxPeerResponseTime(c.maxGetPrefixesResponseTime),
+	)
 	if err != nil {
 		return nil, nil, err
 	}
@@ -55,7 +65,9 @@ func (c *client) GetPrefixes(ctx context.Context, request *GetPrefixesRequest) (
 
 func (c *client) Iterate(ctx context.Context, request *IterateRequest) (*ProofResponse, rpc.PeerFeedback, error) {
 	var rsp ProofResponse
-	pf, err := c.rc.CallOne(ctx, c.mgr.GetBestPeers(), MethodIterate, request, &rsp)
+	pf, err := c.rc.CallOne(ctx, c.mgr.GetBestPeers(), MethodIterate, request, &rsp,
+		rpc.WithMaxPeerResponseTime(c.maxIterateResponseTime),
+	)
 	if err != nil {
 		return nil, nil, err
 	}
@@ -63,6 +75,9 @@ func (c *client) Iterate(ctx context.Context, request *IterateRequest) (*ProofRe
 }
 
 // NewClient creates a new storage pub protocol client.
+//
+// The maximum peer response times follow the node configuration, falling back to the protocol
+// defaults.
 func NewClient(p2p rpc.P2P, chainContext string, runtimeID common.Namespace) Client {
 	pid := protocol.NewRuntimeProtocolID(chainContext, runtimeID, StoragePubProtocolID, StoragePubProtocolVersion)
 	mgr := rpc.NewPeerManager(p2p, pid)
@@ -71,8 +86,24 @@ func NewClient(p2p rpc.P2P, chainContext string, runtimeID common.Namespace) Cli
 
 	p2p.RegisterProtocol(pid, minProtocolPeers, totalProtocolPeers)
 
-	return &client{
-		rc:  rc,
-		mgr: mgr,
+	c := &client{
+		rc:                         rc,
+		mgr:                        mgr,
+		maxGetResponseTime:         MaxGetResponseTime,
+		maxGetPrefixesResponseTime: MaxGetPrefixesResponseTime,
+		maxIterateResponseTime:     MaxIterateResponseTime,
+	}
+
+	cfg := config.GlobalConfig.Storage.PublicRPCClient
+	if cfg.MaxGetResponseTime > 0 {
+		c.maxGetResponseTime = cfg.MaxGetResponseTime
 	}
+	if cfg.MaxGetPrefixesResponseTime > 0 {
+		c.maxGetPrefixesResponseTime = cfg.MaxGetPrefixesResponseTime
+	}
+	if cfg.MaxIterateResponseTime > 0 {
+		c.maxIterateResponseTime = cfg.MaxIterateResponseTime
+	}
+
+	return c
 }
@@ -1,6 +1,8 @@
 package pub
 
 import (
+	"time"
+
 	"github.com/libp2p/go-libp2p/core"
 
 	"github.com/oasisprotocol/oasis-core/go/common/node"
@@ -18,7 +20,8 @@ var StoragePubProtocolVersion = version.Version{Major: 2, Minor: 0, Patch: 0}
 
 // Constants related to the Get method.
 const (
-	MethodGet = "Get"
+	MethodGet          = "Get"
+	MaxGetResponseTime = 2 * time.Second
 )
 
 // GetRequest is a Get request.
@@ -29,7 +32,8 @@ type ProofResponse = syncer.ProofResponse
 
 // Constants related to the GetPrefixes method.
 const (
-	MethodGetPrefixes = "GetPrefixes"
+	MethodGetPrefixes          = "GetPrefixes"
+	MaxGetPrefixesResponseTime = 5 * time.Second
 )
 
 // GetPrefixesRequest is a GetPrefixes request.
@@ -37,7 +41,8 @@ type GetPrefixesRequest = syncer.GetPrefixesRequest
 
 // Constants related to the Iterate method.
 const (
-	MethodIterate = "Iterate"
+	MethodIterate          = "Iterate"
+	MaxIterateResponseTime = 5 * time.Second
 )
 
 // IterateRequest is an Iterate request.
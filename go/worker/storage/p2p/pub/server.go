@@ -44,5 +44,5 @@ func (s *service) HandleRequest(ctx context.Context, method string, body cbor.Ra
 
 // NewServer creates a new storage pub protocol server.
 func NewServer(chainContext string, runtimeID common.Namespace, backend storage.Backend) rpc.Server {
-	return rpc.NewServer(protocol.NewRuntimeProtocolID(chainContext, runtimeID, StoragePubProtocolID, StoragePubProtocolVersion), &service{backend})
+	return rpc.NewServer(protocol.NewRuntimeProtocolID(chainContext, runtimeID, StoragePubProtocolID, StoragePubProtocolVersion), &service{backend}, rpc.ServerOptionsFromConfig()...)
 }
@@ -105,5 +105,5 @@ func (s *service) handleGetCheckpointChunk(ctx context.Context, request *GetChec
 
 // NewServer creates a new storage sync protocol server.
 func NewServer(chainContext string, runtimeID common.Namespace, backend storage.Backend) rpc.Server {
-	return rpc.NewServer(protocol.NewRuntimeProtocolID(chainContext, runtimeID, StorageSyncProtocolID, StorageSyncProtocolVersion), &service{backend})
+	return rpc.NewServer(protocol.NewRuntimeProtocolID(chainContext, runtimeID, StorageSyncProtocolID, StorageSyncProtocolVersion), &service{backend}, rpc.ServerOptionsFromConfig()...)
 }
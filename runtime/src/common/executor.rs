@@ -0,0 +1,197 @@
+//! Per-component task executors.
+use std::{
+    any::Any,
+    future::Future,
+    panic::AssertUnwindSafe,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use futures::FutureExt;
+use slog::{error, Logger};
+use tokio::{
+    sync::{broadcast, Semaphore},
+    task::JoinHandle,
+};
+
+use super::logger::get_logger;
+use crate::future::spawn_limited;
+
+/// Capacity of the task failure event channel.
+const FAILURE_CHANNEL_CAPACITY: usize = 16;
+
+/// Event emitted when a task spawned by an executor fails.
+#[derive(Clone, Debug)]
+pub struct TaskFailure {
+    /// Name of the component that spawned the task.
+    pub component: String,
+    /// Description of the failure.
+    pub reason: String,
+}
+
+/// An executor that spawns tasks on behalf of a single component.
+///
+/// It keeps track of the number of spawned tasks, bounds the number of tasks that can run
+/// concurrently and converts task panics into [`TaskFailure`] events.
+#[derive(Clone)]
+pub struct Executor {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    logger: Logger,
+    component: String,
+    semaphore: Arc<Semaphore>,
+    active: AtomicUsize,
+    spawned: AtomicU64,
+    failed: AtomicU64,
+    failures: broadcast::Sender<TaskFailure>,
+}
+
+impl Executor {
+    /// Create a new executor for the given component.
+    ///
+    /// At most `max_concurrency` tasks will run concurrently; a zero value denotes no limit.
+    pub fn new(component: &str, max_concurrency: usize) -> Self {
+        let permits = match max_concurrency {
+            0 => Semaphore::MAX_PERMITS,
+            n => n,
+        };
+        let (failures, _) = broadcast::channel(FAILURE_CHANNEL_CAPACITY);
+
+        Self {
+            inner: Arc::new(Inner {
+                logger: get_logger("runtime/executor"),
+                component: component.to_string(),
+                semaphore: Arc::new(Semaphore::new(permits)),
+                active: AtomicUsize::new(0),
+                spawned: AtomicU64::new(0),
+                failed: AtomicU64::new(0),
+                failures,
+            }),
+        }
+    }
+
+    /// Spawn a new task.
+    ///
+    /// In case the concurrency limit is reached, the task waits until another task completes
+    /// before it starts running. The returned handle resolves to `None` in case the task failed.
+    pub fn spawn<F>(&self, future: F) -> JoinHandle<Option<F::Output>>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let inner = self.inner.clone();
+        inner.spawned.fetch_add(1, Ordering::Relaxed);
+
+        spawn_limited(inner.semaphore.clone(), async move {
+            inner.active.fetch_add(1, Ordering::SeqCst);
+            let result = AssertUnwindSafe(future).catch_unwind().await;
+            inner.active.fetch_sub(1, Ordering::SeqCst);
+
+            match result {
+                Ok(output) => Some(output),
+                Err(payload) => {
+                    inner.failed.fetch_add(1, Ordering::Relaxed);
+
+                    let reason = panic_reason(&*payload);
+                    error!(inner.logger, "Task failed";
+                        "component" => &inner.component,
+                        "reason" => &reason,
+                    );
+
+                    // Ignore errors as there may be no subscribers.
+                    let _ = inner.failures.send(TaskFailure {
+                        component: inner.component.clone(),
+                        reason,
+                    });
+                    None
+                }
+            }
+        })
+    }
+
+    /// Subscribe to task failure events.
+    pub fn watch_failures(&self) -> broadcast::Receiver<TaskFailure> {
+        self.inner.failures.subscribe()
+    }
+
+    /// Number of currently running tasks.
+    pub fn active_tasks(&self) -> usize {
+        self.inner.active.load(Ordering::SeqCst)
+    }
+
+    /// Total number of spawned tasks.
+    pub fn spawned_tasks(&self) -> u64 {
+        self.inner.spawned.load(Ordering::Relaxed)
+    }
+
+    /// Total number of failed tasks.
+    pub fn failed_tasks(&self) -> u64 {
+        self.inner.failed.load(Ordering::Relaxed)
+    }
+}
+
+/// Returns a description of the given panic payload.
+fn panic_reason(payload: &(dyn Any + Send)) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        format!("task panicked: {msg}")
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        format!("task panicked: {msg}")
+    } else {
+        "task panicked".to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use tokio::sync::Notify;
+
+    use super::*;
+
+    #[test]
+    fn test_executor() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let executor = Executor::new("test", 1);
+            let mut failures = executor.watch_failures();
+
+            // Task output should be returned.
+            let output = executor.spawn(async { 42 }).await.unwrap();
+            assert_eq!(output, Some(42));
+
+            // Concurrency should be limited.
+            let notify = Arc::new(Notify::new());
+            let blocked = {
+                let notify = notify.clone();
+                executor.spawn(async move { notify.notified().await })
+            };
+            while executor.active_tasks() == 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+            let queued = executor.spawn(async { 1 });
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            assert_eq!(executor.active_tasks(), 1, "only one task should run");
+            assert!(!queued.is_finished(), "queued task should wait");
+
+            notify.notify_one();
+            blocked.await.unwrap();
+            assert_eq!(queued.await.unwrap(), Some(1));
+
+            // Panics should be converted to failures.
+            let output = executor.spawn(async { panic!("boom") }).await.unwrap();
+            assert!(output.is_none(), "failed task should not produce output");
+            assert_eq!(executor.failed_tasks(), 1);
+            let failure = failures.recv().await.unwrap();
+            assert_eq!(failure.component, "test");
+            assert_eq!(failure.reason, "task panicked: boom");
+
+            assert_eq!(executor.spawned_tasks(), 4);
+            assert_eq!(executor.active_tasks(), 0);
+        });
+    }
+}
@@ -3,12 +3,15 @@
 #[macro_use]
 pub mod bytes;
 pub mod crypto;
+pub mod executor;
 pub mod key_format;
 pub mod logger;
 pub mod namespace;
 pub mod process;
 pub mod quantity;
 pub mod sgx;
+#[cfg(test)]
+pub(crate) mod testing;
 pub mod time;
 pub mod version;
 pub mod versioned;
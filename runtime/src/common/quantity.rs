@@ -227,11 +227,108 @@ impl cbor::Decode for Quantity {
     }
 }
 
+/// A quantity that, in addition to the canonical byte string encoding, also accepts CBOR unsigned
+/// integers and unsigned bignums when decoding.
+///
+/// This is intended for ingesting data produced by external tooling. Encoding always uses the
+/// canonical representation, so values are normalized on re-encoding.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LenientQuantity(pub Quantity);
+
+impl From<Quantity> for LenientQuantity {
+    fn from(q: Quantity) -> LenientQuantity {
+        LenientQuantity(q)
+    }
+}
+
+impl From<LenientQuantity> for Quantity {
+    fn from(q: LenientQuantity) -> Quantity {
+        q.0
+    }
+}
+
+impl fmt::Display for LenientQuantity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl cbor::Encode for LenientQuantity {
+    fn is_empty(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    fn into_cbor_value(self) -> cbor::Value {
+        cbor::Encode::into_cbor_value(self.0)
+    }
+}
+
+impl cbor::Decode for LenientQuantity {
+    fn try_default() -> Result<Self, cbor::DecodeError> {
+        Ok(Default::default())
+    }
+
+    fn try_from_cbor_value(value: cbor::Value) -> Result<Self, cbor::DecodeError> {
+        match value {
+            cbor::Value::Unsigned(v) => Ok(LenientQuantity(Quantity::from(v))),
+            // Unsigned bignum (RFC 8949, section 3.4.3).
+            cbor::Value::Tag(2, inner) => match *inner {
+                cbor::Value::ByteString(data) => {
+                    Ok(LenientQuantity(Quantity(BigUint::from_bytes_be(&data))))
+                }
+                _ => Err(cbor::DecodeError::UnexpectedType),
+            },
+            value => <Quantity as cbor::Decode>::try_from_cbor_value(value).map(LenientQuantity),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use rustc_hex::ToHex;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rustc_hex::{FromHex, ToHex};
+
+    use crate::common::{
+        quantity::{LenientQuantity, Quantity},
+        testing::test_seed,
+    };
+
+    #[test]
+    fn test_serialization_random() {
+        let seed = test_seed();
+        let mut rng = StdRng::seed_from_u64(seed);
 
-    use crate::common::quantity::Quantity;
+        for _ in 0..1000 {
+            // Bias towards smaller values to cover all encoded lengths.
+            let bits = rng.gen_range(0..=128);
+            let v: u128 = rng.gen::<u128>().checked_shr(128 - bits).unwrap_or(0);
+            let q = Quantity::from(v);
+
+            let enc = cbor::to_vec(q.clone());
+            let dec: Quantity = cbor::from_slice(&enc)
+                .unwrap_or_else(|_| panic!("deserialization should succeed (seed: {})", seed));
+            assert_eq!(dec, q, "serialization should round-trip (seed: {})", seed);
+        }
+    }
+
+    #[test]
+    fn test_ops_random() {
+        let seed = test_seed();
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        for _ in 0..1000 {
+            let a = Quantity::from(rng.gen::<u64>());
+            let b = Quantity::from(rng.gen::<u64>());
+
+            let sum = a.clone() + b.clone();
+            assert_eq!(sum.checked_sub(&b), Some(a.clone()), "seed: {}", seed);
+
+            let product = a.clone() * b.clone();
+            if b != Quantity::from(0u32) {
+                assert_eq!(product.checked_div(&b), Some(a.clone()), "seed: {}", seed);
+            }
+        }
+    }
 
     #[test]
     fn test_serialization() {
@@ -256,6 +353,39 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_lenient_serialization() {
+        let cases = vec![
+            // Canonical encoding.
+            (1000u128, "4203e8"),
+            // Unsigned integers.
+            (0, "00"),
+            (10, "0a"),
+            (1000, "1903e8"),
+            (18446744073709551615, "1bffffffffffffffff"),
+            // Unsigned bignum.
+            (18446744073709551616, "c249010000000000000000"),
+        ];
+
+        for tc in cases {
+            let enc: Vec<u8> = tc.1.from_hex().unwrap();
+            let dec: LenientQuantity =
+                cbor::from_slice(&enc).expect("deserialization should succeed");
+            assert_eq!(dec, LenientQuantity(Quantity::from(tc.0)));
+
+            // Re-encoding should use the canonical encoding.
+            assert_eq!(
+                cbor::to_vec(dec),
+                cbor::to_vec(Quantity::from(tc.0)),
+                "re-encoding should be canonical"
+            );
+        }
+
+        // Strict decoding should reject integers.
+        let enc: Vec<u8> = "1903e8".from_hex().unwrap();
+        cbor::from_slice::<Quantity>(&enc).expect_err("strict deserialization should fail");
+    }
+
     #[test]
     fn test_ops() {
         // Add.
@@ -0,0 +1,17 @@
+//! Testing helpers.
+
+/// Name of the environment variable that overrides the seed of randomized tests.
+const TEST_SEED_ENV: &str = "OASIS_TEST_SEED";
+
+/// Returns the seed that randomized tests should use.
+///
+/// The seed is fixed so that test runs are reproducible, but may be overridden via the
+/// `OASIS_TEST_SEED` environment variable to explore other inputs.
+pub(crate) fn test_seed() -> u64 {
+    std::env::var(TEST_SEED_ENV)
+        .map(|seed| {
+            seed.parse()
+                .unwrap_or_else(|_| panic!("{} should be a valid u64", TEST_SEED_ENV))
+        })
+        .unwrap_or(0)
+}
@@ -17,6 +17,8 @@ pub struct Config {
     pub persist_check_tx_state: bool,
     /// Whether TEE freshness is verified with freshness proofs.
     pub freshness_proofs: bool,
+    /// The maximum number of host requests processed concurrently. A zero value denotes no limit.
+    pub max_concurrent_requests: usize,
 }
 
 /// Storage-related configuration.
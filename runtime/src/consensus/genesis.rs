@@ -0,0 +1,760 @@
+//! Consensus genesis structures.
+//!
+//! # Note
+//!
+//! This **MUST** be kept in sync with go/consensus/genesis and go/genesis/api.
+//!
+use std::collections::{BTreeMap, BTreeSet};
+
+use num_traits::Zero;
+use thiserror::Error;
+
+use crate::{
+    common::{
+        crypto::{hash::Hash, signature::PublicKey},
+        quantity::Quantity,
+    },
+    consensus::{
+        address::Address,
+        staking::{self, Account},
+        transaction::{Costs, Gas},
+    },
+    types::{self, CodedError},
+};
+
+/// Gas operation identifier for costing each transaction byte.
+pub const GAS_OP_TX_BYTE: &str = "tx_byte";
+
+/// Maximum length of the token's ticker symbol.
+pub const TOKEN_SYMBOL_MAX_LENGTH: usize = 8;
+
+/// Maximum value of the token's value base-10 exponent.
+pub const TOKEN_VALUE_EXPONENT_MAX_VALUE: u8 = 20;
+
+/// A unique module name for the genesis module.
+pub const MODULE_NAME: &str = "genesis";
+
+/// Errors emitted by the genesis module.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("genesis: sanity check failed: {0}")]
+    SanityCheckFailed(String),
+}
+
+impl CodedError for Error {
+    fn module_name(&self) -> &str {
+        MODULE_NAME
+    }
+
+    fn code(&self) -> u32 {
+        match self {
+            Error::SanityCheckFailed(_) => 1,
+        }
+    }
+}
+
+impl From<Error> for types::Error {
+    fn from(e: Error) -> Self {
+        e.to_error()
+    }
+}
+
+/// Consensus genesis state.
+#[derive(Clone, Debug, Default, PartialEq, Eq, cbor::Encode, cbor::Decode)]
+pub struct Genesis {
+    /// Name of the consensus backend.
+    pub backend: String,
+    /// Consensus backend parameters.
+    #[cbor(rename = "params")]
+    pub parameters: Parameters,
+}
+
+/// Consensus backend parameters.
+#[derive(Clone, Debug, Default, PartialEq, Eq, cbor::Encode, cbor::Decode)]
+pub struct Parameters {
+    /// Timeout commit (in nanoseconds).
+    pub timeout_commit: u64,
+    pub skip_timeout_commit: bool,
+    /// Empty block interval (in nanoseconds).
+    pub empty_block_interval: u64,
+
+    pub max_tx_size: u64,
+    pub max_block_size: u64,
+    pub max_block_gas: Gas,
+    pub max_evidence_size: u64,
+
+    /// Expected state checkpoint interval (in blocks).
+    pub state_checkpoint_interval: u64,
+    /// Expected minimum number of state checkpoints to keep.
+    #[cbor(optional)]
+    pub state_checkpoint_num_kept: u64,
+    /// Chunk size parameter for checkpoint creation.
+    #[cbor(optional)]
+    pub state_checkpoint_chunk_size: u64,
+
+    /// Base transaction gas costs.
+    #[cbor(optional)]
+    pub gas_costs: Costs,
+
+    /// Network-wide public key blacklist.
+    #[cbor(optional)]
+    pub public_key_blacklist: Vec<PublicKey>,
+}
+
+impl Parameters {
+    /// Performs basic sanity checks on the consensus parameters.
+    pub fn sanity_check(&self) -> Result<(), Error> {
+        if self.timeout_commit < 1_000_000 && !self.skip_timeout_commit {
+            return Err(Error::SanityCheckFailed(
+                "timeout commit must be >= 1ms".to_string(),
+            ));
+        }
+
+        if self.state_checkpoint_interval > 0 {
+            if self.state_checkpoint_interval < 1000 {
+                return Err(Error::SanityCheckFailed(
+                    "state checkpoint interval must be >= 1000".to_string(),
+                ));
+            }
+            if self.state_checkpoint_num_kept == 0 {
+                return Err(Error::SanityCheckFailed(
+                    "number of kept state checkpoints must be > 0".to_string(),
+                ));
+            }
+            if self.state_checkpoint_chunk_size < 1024 * 1024 {
+                return Err(Error::SanityCheckFailed(
+                    "state checkpoint chunk size must be >= 1 MiB".to_string(),
+                ));
+            }
+        }
+
+        // Check for duplicate entries in the public key blacklist.
+        let mut seen = BTreeSet::new();
+        for pk in &self.public_key_blacklist {
+            if !seen.insert(pk) {
+                return Err(Error::SanityCheckFailed(format!(
+                    "redundant blacklisted public key: '{}'",
+                    pk
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Genesis document.
+///
+/// Sections that are not interpreted by the runtime are kept as opaque CBOR values so that the
+/// document hash is preserved. Their defaults match the encoding of the corresponding Go
+/// zero-value structures.
+///
+/// The runtime does not export consensus state into genesis documents. Use the
+/// `oasis-node debug dumpdb` command to dump the on-disk consensus state into a genesis document.
+#[derive(Clone, Debug, PartialEq, Eq, cbor::Encode, cbor::Decode)]
+pub struct Document {
+    /// Block height at which the document was generated.
+    pub height: i64,
+    /// Time the genesis block was constructed (in seconds since the UNIX epoch).
+    #[cbor(rename = "genesis_time")]
+    pub time: i64,
+    /// Identifier of the chain.
+    pub chain_id: String,
+    /// Registry genesis state.
+    pub registry: cbor::Value,
+    /// Roothash genesis state.
+    pub roothash: cbor::Value,
+    /// Staking genesis state.
+    pub staking: staking::Genesis,
+    /// Key manager genesis state.
+    pub keymanager: cbor::Value,
+    /// Scheduler genesis state.
+    pub scheduler: cbor::Value,
+    /// Beacon genesis state.
+    pub beacon: cbor::Value,
+    /// Governance genesis state.
+    pub governance: cbor::Value,
+    /// Consensus genesis state.
+    pub consensus: Genesis,
+    /// Arbitrary extra data that is part of the genesis block but is otherwise ignored by the
+    /// protocol.
+    pub extra_data: Option<BTreeMap<String, Vec<u8>>>,
+}
+
+impl Default for Document {
+    fn default() -> Self {
+        let zero = || cbor::Value::Unsigned(0);
+        let empty_params = || cbor_map(vec![("params", cbor_map(vec![]))]);
+
+        Self {
+            height: 0,
+            time: 0,
+            chain_id: String::new(),
+            registry: empty_params(),
+            roothash: cbor_map(vec![(
+                "params",
+                cbor_map(vec![
+                    ("max_runtime_messages", zero()),
+                    ("max_in_runtime_messages", zero()),
+                    ("max_evidence_age", zero()),
+                ]),
+            )]),
+            staking: Default::default(),
+            keymanager: empty_params(),
+            scheduler: cbor_map(vec![(
+                "params",
+                cbor_map(vec![
+                    ("min_validators", zero()),
+                    ("max_validators", zero()),
+                    ("max_validators_per_entity", zero()),
+                    (
+                        "reward_factor_epoch_election_any",
+                        cbor::Encode::into_cbor_value(Quantity::zero()),
+                    ),
+                ]),
+            )]),
+            beacon: cbor_map(vec![
+                ("base", zero()),
+                (
+                    "params",
+                    cbor_map(vec![("backend", cbor::Value::TextString(String::new()))]),
+                ),
+            ]),
+            governance: empty_params(),
+            consensus: Default::default(),
+            extra_data: None,
+        }
+    }
+}
+
+/// Builds a CBOR map with text keys.
+fn cbor_map(entries: Vec<(&str, cbor::Value)>) -> cbor::Value {
+    cbor::Value::Map(
+        entries
+            .into_iter()
+            .map(|(key, value)| (cbor::Value::TextString(key.to_string()), value))
+            .collect(),
+    )
+}
+
+impl Document {
+    /// Create a new genesis document builder.
+    pub fn builder() -> DocumentBuilder {
+        DocumentBuilder::new()
+    }
+
+    /// Cryptographic hash of the encoded genesis document.
+    pub fn hash(&self) -> Hash {
+        Hash::digest_bytes(&cbor::to_vec(self.clone()))
+    }
+
+    /// A string that can be used as a chain domain separation context.
+    ///
+    /// Changing this (or any data it is derived from) invalidates all signatures that use chain
+    /// domain separation.
+    pub fn chain_context(&self) -> String {
+        format!("{:x}", self.hash())
+    }
+
+    /// Performs basic sanity checks on the genesis document.
+    pub fn sanity_check(&self) -> Result<(), Error> {
+        if self.chain_id.is_empty() {
+            return Err(Error::SanityCheckFailed(
+                "chain ID must not be empty".to_string(),
+            ));
+        }
+
+        self.consensus.parameters.sanity_check()?;
+        sanity_check_staking(&self.staking)
+    }
+}
+
+fn sanity_check_staking(g: &staking::Genesis) -> Result<(), Error> {
+    if g.token_symbol.is_empty() {
+        return Err(Error::SanityCheckFailed(
+            "token symbol is empty".to_string(),
+        ));
+    }
+    if g.token_symbol.len() > TOKEN_SYMBOL_MAX_LENGTH {
+        return Err(Error::SanityCheckFailed(
+            "token symbol exceeds maximum length".to_string(),
+        ));
+    }
+    if !g.token_symbol.chars().all(|c| c.is_ascii_uppercase()) {
+        return Err(Error::SanityCheckFailed(
+            "token symbol should only contain characters A-Z".to_string(),
+        ));
+    }
+    if g.token_value_exponent > TOKEN_VALUE_EXPONENT_MAX_VALUE {
+        return Err(Error::SanityCheckFailed(
+            "token value exponent is invalid".to_string(),
+        ));
+    }
+
+    // Check if the total supply adds up: governance deposits + common pool + last block fees +
+    // all balances in the ledger.
+    let mut total = Quantity::zero();
+    for (addr, account) in &g.ledger {
+        if !account.escrow.stake_accumulator.claims.is_empty() {
+            return Err(Error::SanityCheckFailed(format!(
+                "non-empty stake accumulator for account {:?}",
+                addr
+            )));
+        }
+
+        total += &account.general.balance;
+        total += &account.escrow.active.balance;
+        total += &account.escrow.debonding.balance;
+    }
+    total += &g.governance_deposits;
+    total += &g.common_pool;
+    total += &g.last_block_fees;
+    if total != g.total_supply {
+        return Err(Error::SanityCheckFailed(format!(
+            "balances ({}) do not add up to total supply ({})",
+            total, g.total_supply
+        )));
+    }
+
+    // Delegations and debonding delegations must only be specified for existing accounts.
+    for addr in g.delegations.keys() {
+        if !g.ledger.contains_key(addr) {
+            return Err(Error::SanityCheckFailed(format!(
+                "delegation specified for a nonexisting account: {:?}",
+                addr
+            )));
+        }
+    }
+    for addr in g.debonding_delegations.keys() {
+        if !g.ledger.contains_key(addr) {
+            return Err(Error::SanityCheckFailed(format!(
+                "debonding delegation specified for a nonexisting account: {:?}",
+                addr
+            )));
+        }
+    }
+
+    // All shares of all (debonding) delegations for a given account must add up to the account's
+    // (debonding) escrow total shares.
+    for (addr, account) in &g.ledger {
+        let shares = g
+            .delegations
+            .get(addr)
+            .into_iter()
+            .flat_map(|d| d.values())
+            .fold(Quantity::zero(), |acc, d| acc + &d.shares);
+        if shares != account.escrow.active.total_shares {
+            return Err(Error::SanityCheckFailed(format!(
+                "delegation shares for account {:?} do not match active escrow total shares",
+                addr
+            )));
+        }
+
+        let shares = g
+            .debonding_delegations
+            .get(addr)
+            .into_iter()
+            .flat_map(|d| d.values().flatten())
+            .fold(Quantity::zero(), |acc, d| acc + &d.shares);
+        if shares != account.escrow.debonding.total_shares {
+            return Err(Error::SanityCheckFailed(format!(
+                "debonding delegation shares for account {:?} do not match debonding escrow total shares",
+                addr
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Genesis document builder.
+///
+/// The total supply is computed automatically from the configured balances when building the
+/// document.
+pub struct DocumentBuilder {
+    document: Document,
+}
+
+impl DocumentBuilder {
+    /// Create a new genesis document builder with defaults suitable for tests.
+    pub fn new() -> Self {
+        Self {
+            document: Document {
+                chain_id: "test".to_string(),
+                staking: staking::Genesis {
+                    token_symbol: "TEST".to_string(),
+                    ..Default::default()
+                },
+                consensus: Genesis {
+                    backend: "tendermint".to_string(),
+                    parameters: Parameters {
+                        timeout_commit: 1_000_000,
+                        ..Default::default()
+                    },
+                },
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Set the genesis block height.
+    pub fn with_height(mut self, height: i64) -> Self {
+        self.document.height = height;
+        self
+    }
+
+    /// Set the genesis time (in seconds since the UNIX epoch).
+    pub fn with_time(mut self, time: i64) -> Self {
+        self.document.time = time;
+        self
+    }
+
+    /// Set the chain identifier.
+    pub fn with_chain_id(mut self, chain_id: &str) -> Self {
+        self.document.chain_id = chain_id.to_string();
+        self
+    }
+
+    /// Set the consensus backend parameters.
+    pub fn with_consensus_parameters(mut self, parameters: Parameters) -> Self {
+        self.document.consensus.parameters = parameters;
+        self
+    }
+
+    /// Set the staking consensus parameters.
+    pub fn with_staking_parameters(mut self, parameters: staking::ConsensusParameters) -> Self {
+        self.document.staking.parameters = parameters;
+        self
+    }
+
+    /// Set the token's ticker symbol and value exponent.
+    pub fn with_token(mut self, symbol: &str, value_exponent: u8) -> Self {
+        self.document.staking.token_symbol = symbol.to_string();
+        self.document.staking.token_value_exponent = value_exponent;
+        self
+    }
+
+    /// Add an account to the staking ledger.
+    pub fn with_account(mut self, address: Address, account: Account) -> Self {
+        self.document.staking.ledger.insert(address, account);
+        self
+    }
+
+    /// Set the common pool balance.
+    pub fn with_common_pool(mut self, amount: Quantity) -> Self {
+        self.document.staking.common_pool = amount;
+        self
+    }
+
+    /// Add arbitrary extra data to the genesis document.
+    pub fn with_extra_data(mut self, key: &str, value: Vec<u8>) -> Self {
+        self.document
+            .extra_data
+            .get_or_insert_with(BTreeMap::new)
+            .insert(key.to_string(), value);
+        self
+    }
+
+    /// Build the genesis document.
+    pub fn build(mut self) -> Document {
+        let staking = &mut self.document.staking;
+        let mut total = staking
+            .ledger
+            .values()
+            .fold(Quantity::zero(), |acc, account| {
+                acc + &account.general.balance
+                    + &account.escrow.active.balance
+                    + &account.escrow.debonding.balance
+            });
+        total += &staking.governance_deposits;
+        total += &staking.common_pool;
+        total += &staking.last_block_fees;
+        staking.total_supply = total;
+
+        self.document
+    }
+}
+
+impl Default for DocumentBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::consensus::staking::{Delegation, EscrowAccount, GeneralAccount, SharePool};
+
+    fn test_address(pk: &str) -> Address {
+        Address::from_pk(&PublicKey::from(pk))
+    }
+
+    #[test]
+    fn test_builder() {
+        let addr1 =
+            test_address("aaafffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff");
+        let addr2 =
+            test_address("bbbfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff");
+
+        let mut doc = Document::builder()
+            .with_chain_id("test-chain")
+            .with_height(10)
+            .with_account(
+                addr1.clone(),
+                Account {
+                    general: GeneralAccount {
+                        balance: Quantity::from(100u32),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            )
+            .with_account(
+                addr2.clone(),
+                Account {
+                    escrow: EscrowAccount {
+                        active: SharePool {
+                            balance: Quantity::from(50u32),
+                            total_shares: Quantity::from(50u32),
+                        },
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            )
+            .with_common_pool(Quantity::from(1000u32))
+            .build();
+
+        assert_eq!(doc.chain_id, "test-chain");
+        assert_eq!(doc.height, 10);
+        assert_eq!(doc.staking.total_supply, Quantity::from(1150u32));
+        doc.sanity_check()
+            .expect_err("sanity check should fail due to missing delegations");
+
+        doc.staking.delegations.insert(
+            addr2,
+            [(
+                addr1,
+                Delegation {
+                    shares: Quantity::from(50u32),
+                },
+            )]
+            .iter()
+            .cloned()
+            .collect(),
+        );
+        doc.sanity_check().expect("sanity check should pass");
+    }
+
+    #[test]
+    fn test_hash() {
+        let doc = Document::builder().with_chain_id("test-chain").build();
+        assert_eq!(
+            doc.hash(),
+            doc.clone().hash(),
+            "hash should be deterministic"
+        );
+        assert_eq!(doc.chain_context(), format!("{:x}", doc.hash()));
+
+        let enc = cbor::to_vec(doc.clone());
+        let dec: Document = cbor::from_slice(&enc).expect("document should deserialize");
+        assert_eq!(dec.hash(), doc.hash(), "hash should survive round-trip");
+
+        let other = Document::builder().with_chain_id("other-chain").build();
+        assert_ne!(
+            doc.chain_context(),
+            other.chain_context(),
+            "chain context should depend on the document"
+        );
+    }
+
+    #[test]
+    fn test_hash_vector() {
+        use cbor::Encode;
+
+        // NOTE: This vector must be kept in sync with TestGenesisChainContext in
+        //       go/genesis/genesis_test.go.
+        let doc = Document {
+            height: 1,
+            time: 1574858284,
+            chain_id: "test: oasis-core tests".to_string(),
+            registry: cbor_map(vec![(
+                "params",
+                cbor_map(vec![
+                    ("debug_allow_unroutable_addresses", true.into_cbor_value()),
+                    ("debug_bypass_stake", true.into_cbor_value()),
+                    (
+                        "enable_runtime_governance_models",
+                        cbor::Value::Map(
+                            (1u8..=3)
+                                .map(|gm| (gm.into_cbor_value(), true.into_cbor_value()))
+                                .collect(),
+                        ),
+                    ),
+                ]),
+            )]),
+            scheduler: cbor_map(vec![(
+                "params",
+                cbor_map(vec![
+                    ("min_validators", 1u64.into_cbor_value()),
+                    ("max_validators", 100u64.into_cbor_value()),
+                    ("max_validators_per_entity", 100u64.into_cbor_value()),
+                    ("debug_bypass_stake", true.into_cbor_value()),
+                    (
+                        "reward_factor_epoch_election_any",
+                        Quantity::zero().into_cbor_value(),
+                    ),
+                ]),
+            )]),
+            beacon: cbor_map(vec![
+                ("base", 0u64.into_cbor_value()),
+                (
+                    "params",
+                    cbor_map(vec![
+                        ("backend", "insecure".to_string().into_cbor_value()),
+                        ("debug_mock_backend", true.into_cbor_value()),
+                        ("insecure_parameters", cbor_map(vec![])),
+                    ]),
+                ),
+            ]),
+            governance: cbor_map(vec![(
+                "params",
+                cbor_map(vec![
+                    ("stake_threshold", 90u64.into_cbor_value()),
+                    ("voting_period", 100u64.into_cbor_value()),
+                    ("upgrade_min_epoch_diff", 200u64.into_cbor_value()),
+                    ("upgrade_cancel_min_epoch_diff", 200u64.into_cbor_value()),
+                ]),
+            )]),
+            consensus: Genesis {
+                backend: "tendermint".to_string(),
+                parameters: Parameters {
+                    timeout_commit: 1_000_000,
+                    skip_timeout_commit: true,
+                    ..Default::default()
+                },
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            doc.chain_context(),
+            "a9dfaa6890772f10bde7b0c34214b1faf22fdecd334d2199f30414f3a30aa3f6",
+            "hash should match the Go genesis document hash"
+        );
+
+        // NOTE: This vector must be kept in sync with TestGenesisChainContextDefaults in
+        //       go/genesis/genesis_test.go.
+        let doc = Document::builder()
+            .with_chain_id("test-chain")
+            .with_height(1)
+            .with_time(1_600_000_000)
+            .with_token("TEST", 6)
+            .with_common_pool(Quantity::from(1000u32))
+            .build();
+        assert_eq!(
+            doc.chain_context(),
+            "844fd0cc98c059e1d24d342926a98f1151ffaa6cb994694fae73646a0f4570e6",
+            "hash of a document with default sections should match the Go genesis document hash"
+        );
+    }
+
+    #[test]
+    fn test_duplicate_keys() {
+        let doc = Document::builder().with_extra_data("a", vec![1]).build();
+        let enc = cbor::to_vec(doc.clone());
+        let dec: Document = cbor::from_slice(&enc).expect("document should deserialize");
+        assert_eq!(dec, doc);
+
+        // Replace the extra data map {"a": h'01'} with {"a": h'01', "a": h'02'}.
+        let good: &[u8] = &[0xa1, 0x61, 0x61, 0x41, 0x01];
+        let dup: &[u8] = &[0xa2, 0x61, 0x61, 0x41, 0x01, 0x61, 0x61, 0x41, 0x02];
+        let pos = enc
+            .windows(good.len())
+            .position(|w| w == good)
+            .expect("extra data should be encoded");
+        let mut malformed = enc[..pos].to_vec();
+        malformed.extend_from_slice(dup);
+        malformed.extend_from_slice(&enc[pos + good.len()..]);
+
+        cbor::from_slice::<Document>(&malformed)
+            .expect_err("document with duplicate keys should be rejected");
+    }
+
+    #[test]
+    fn test_sanity_check() {
+        let addr = test_address("aaafffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff");
+
+        Document::builder()
+            .build()
+            .sanity_check()
+            .expect("default document should pass sanity check");
+
+        // Empty chain ID.
+        Document::builder()
+            .with_chain_id("")
+            .build()
+            .sanity_check()
+            .expect_err("sanity check should fail for empty chain ID");
+
+        // Invalid token symbol.
+        Document::builder()
+            .with_token("test", 6)
+            .build()
+            .sanity_check()
+            .expect_err("sanity check should fail for lowercase token symbol");
+        Document::builder()
+            .with_token("TOOLONGSYMBOL", 6)
+            .build()
+            .sanity_check()
+            .expect_err("sanity check should fail for long token symbol");
+        Document::builder()
+            .with_token("TEST", 21)
+            .build()
+            .sanity_check()
+            .expect_err("sanity check should fail for invalid token value exponent");
+
+        // Invalid total supply.
+        let mut doc = Document::builder()
+            .with_account(
+                addr.clone(),
+                Account {
+                    general: GeneralAccount {
+                        balance: Quantity::from(100u32),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            )
+            .build();
+        doc.sanity_check().expect("sanity check should pass");
+        doc.staking.total_supply = Quantity::from(99u32);
+        doc.sanity_check()
+            .expect_err("sanity check should fail for invalid total supply");
+
+        // Delegation to nonexisting account.
+        let mut doc = Document::builder().build();
+        doc.staking.delegations.insert(addr, BTreeMap::new());
+        doc.sanity_check()
+            .expect_err("sanity check should fail for delegation to nonexisting account");
+
+        // Invalid consensus parameters.
+        Document::builder()
+            .with_consensus_parameters(Parameters::default())
+            .build()
+            .sanity_check()
+            .expect_err("sanity check should fail for invalid timeout commit");
+
+        let pk =
+            PublicKey::from("aaafffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff");
+        Document::builder()
+            .with_consensus_parameters(Parameters {
+                timeout_commit: 1_000_000,
+                public_key_blacklist: vec![pk, pk],
+                ..Default::default()
+            })
+            .build()
+            .sanity_check()
+            .expect_err("sanity check should fail for duplicate blacklisted keys");
+    }
+}
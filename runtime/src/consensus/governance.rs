@@ -6,11 +6,21 @@
 //!
 use std::collections::BTreeMap;
 
+use num_traits::Zero;
+use thiserror::Error;
+
 use crate::{
     common::{quantity::Quantity, version::ProtocolVersions},
-    consensus::beacon::EpochTime,
+    consensus::{address::Address, beacon::EpochTime, transaction::Costs},
 };
 
+/// Errors emitted by the governance module.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("governance: invalid closing proposal state: {0}")]
+    InvalidProposalState(String),
+}
+
 /// A governance vote.
 #[derive(
     Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, cbor::Encode, cbor::Decode,
@@ -71,11 +81,159 @@ pub struct ProposalContent {
     pub change_parameters: Option<ChangeParametersProposal>,
 }
 
+/// State of a governance proposal.
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, cbor::Encode, cbor::Decode,
+)]
+#[repr(u8)]
+pub enum ProposalState {
+    /// Invalid state that should never be explicitly set.
+    #[default]
+    Invalid = 0,
+    /// Proposal is open for voting.
+    Active = 1,
+    /// Proposal has been accepted.
+    Passed = 2,
+    /// Proposal has been rejected.
+    Rejected = 3,
+    /// Proposal has passed, but its execution failed.
+    Failed = 4,
+}
+
+/// A consensus layer governance proposal.
+#[derive(Clone, Debug, Default, PartialEq, Eq, cbor::Encode, cbor::Decode)]
+pub struct Proposal {
+    /// Unique identifier of the proposal.
+    pub id: u64,
+    /// Address of the proposal submitter.
+    pub submitter: Address,
+    /// State of the proposal.
+    pub state: ProposalState,
+    /// Deposit attached to the proposal.
+    pub deposit: Quantity,
+
+    /// Content of the proposal.
+    pub content: ProposalContent,
+
+    /// Epoch at which the proposal was created.
+    pub created_at: EpochTime,
+    /// Epoch at which the proposal will close and votes will be tallied.
+    pub closes_at: EpochTime,
+    /// Final tallied results after the voting period has ended.
+    #[cbor(optional)]
+    pub results: Option<BTreeMap<Vote, Quantity>>,
+    /// Number of invalid votes after tallying.
+    #[cbor(optional)]
+    pub invalid_votes: u64,
+}
+
+impl Proposal {
+    /// Returns the sum of all votes.
+    pub fn voted_sum(&self) -> Quantity {
+        self.results
+            .iter()
+            .flat_map(|results| results.values())
+            .fold(Quantity::default(), |acc, q| acc + q)
+    }
+
+    /// Closes an active proposal based on the vote results and specified voting parameters.
+    ///
+    /// The proposal is accepted iff the percentage of yes votes relative to total voting power
+    /// is at least `stake_threshold`. Otherwise the proposal is rejected.
+    pub fn close_proposal(
+        &mut self,
+        total_voting_stake: &Quantity,
+        stake_threshold: u8,
+    ) -> Result<(), Error> {
+        if self.state != ProposalState::Active {
+            return Err(Error::InvalidProposalState(format!(
+                "expected: {:?}, got: {:?}",
+                ProposalState::Active,
+                self.state
+            )));
+        }
+        let results = match self.results {
+            Some(ref results) => results,
+            None => {
+                return Err(Error::InvalidProposalState(
+                    "results not initialized".to_string(),
+                ))
+            }
+        };
+        if total_voting_stake.is_zero() {
+            return Err(Error::InvalidProposalState(
+                "total voting stake is zero".to_string(),
+            ));
+        }
+        // Ensure voted stake is not more than the total possible voting stake.
+        if &self.voted_sum() > total_voting_stake {
+            return Err(Error::InvalidProposalState(
+                "voted stake greater than total possible voting stake".to_string(),
+            ));
+        }
+
+        let voted_yes_stake = results.get(&Vote::Yes).cloned().unwrap_or_default();
+        if voted_yes_stake.is_zero() {
+            // If there's no yes votes, we can early reject the vote.
+            self.state = ProposalState::Rejected;
+            return Ok(());
+        }
+
+        // Calculate percentage of yes votes vs the sum of validator stake.
+        let voted_yes_percentage = (voted_yes_stake * 100)
+            .checked_div(total_voting_stake)
+            .expect("total voting stake is not zero");
+        if voted_yes_percentage < Quantity::from(stake_threshold) {
+            self.state = ProposalState::Rejected;
+            return Ok(());
+        }
+
+        self.state = ProposalState::Passed;
+        Ok(())
+    }
+}
+
+/// A vote cast by a voter.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, cbor::Encode, cbor::Decode)]
+pub struct VoteEntry {
+    pub voter: Address,
+    pub vote: Vote,
+}
+
+/// Governance consensus parameters.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, cbor::Encode, cbor::Decode)]
+pub struct ConsensusParameters {
+    /// Governance transaction gas costs.
+    #[cbor(optional)]
+    pub gas_costs: Costs,
+    /// Number of base units that are deposited when creating a new proposal.
+    #[cbor(optional)]
+    pub min_proposal_deposit: Quantity,
+    /// Number of epochs after which the voting for a proposal is closed and the votes are tallied.
+    #[cbor(optional)]
+    pub voting_period: EpochTime,
+    /// Minimum percentage of yes votes in terms of total voting power when the proposal expires
+    /// in order for a proposal to be accepted.
+    #[cbor(optional)]
+    pub stake_threshold: u8,
+    /// Minimum number of epochs between the current epoch and the proposed upgrade epoch for the
+    /// upgrade proposal to be valid.
+    #[cbor(optional)]
+    pub upgrade_min_epoch_diff: EpochTime,
+    /// Minimum number of epochs between the current epoch and the proposed upgrade epoch for the
+    /// upgrade cancellation proposal to be valid.
+    #[cbor(optional)]
+    pub upgrade_cancel_min_epoch_diff: EpochTime,
+    /// Whether change parameters proposals are allowed.
+    #[cbor(optional)]
+    pub enable_change_parameters_proposal: bool,
+}
+
 // Allowed governance consensus parameter changes.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, cbor::Encode, cbor::Decode)]
 pub struct ConsensusParameterChanges {
     #[cbor(optional)]
-    pub gas_costs: BTreeMap<String, u64>,
+    pub gas_costs: Option<Costs>,
     #[cbor(optional)]
     pub min_proposal_deposit: Option<Quantity>,
     #[cbor(optional)]
@@ -90,6 +248,33 @@ pub struct ConsensusParameterChanges {
     pub enable_change_parameters_proposal: Option<bool>,
 }
 
+impl ConsensusParameterChanges {
+    /// Applies changes to the given consensus parameters.
+    pub fn apply(&self, params: &mut ConsensusParameters) {
+        if let Some(gas_costs) = &self.gas_costs {
+            params.gas_costs = gas_costs.clone();
+        }
+        if let Some(min_proposal_deposit) = &self.min_proposal_deposit {
+            params.min_proposal_deposit = min_proposal_deposit.clone();
+        }
+        if let Some(voting_period) = self.voting_period {
+            params.voting_period = voting_period;
+        }
+        if let Some(stake_threshold) = self.stake_threshold {
+            params.stake_threshold = stake_threshold;
+        }
+        if let Some(upgrade_min_epoch_diff) = self.upgrade_min_epoch_diff {
+            params.upgrade_min_epoch_diff = upgrade_min_epoch_diff;
+        }
+        if let Some(upgrade_cancel_min_epoch_diff) = self.upgrade_cancel_min_epoch_diff {
+            params.upgrade_cancel_min_epoch_diff = upgrade_cancel_min_epoch_diff;
+        }
+        if let Some(enable_change_parameters_proposal) = self.enable_change_parameters_proposal {
+            params.enable_change_parameters_proposal = enable_change_parameters_proposal;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,6 +321,93 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_close_proposal() {
+        let proposal = |results: Vec<(Vote, u64)>| Proposal {
+            state: ProposalState::Active,
+            results: Some(
+                results
+                    .into_iter()
+                    .map(|(v, q)| (v, Quantity::from(q)))
+                    .collect(),
+            ),
+            ..Default::default()
+        };
+
+        // Passed.
+        let mut p = proposal(vec![(Vote::Yes, 80), (Vote::No, 10)]);
+        p.close_proposal(&Quantity::from(100u64), 67)
+            .expect("closing proposal should work");
+        assert_eq!(p.state, ProposalState::Passed);
+
+        // Rejected due to stake threshold.
+        let mut p = proposal(vec![(Vote::Yes, 60), (Vote::Abstain, 40)]);
+        p.close_proposal(&Quantity::from(100u64), 67)
+            .expect("closing proposal should work");
+        assert_eq!(p.state, ProposalState::Rejected);
+
+        // Rejected due to no yes votes.
+        let mut p = proposal(vec![(Vote::No, 100)]);
+        p.close_proposal(&Quantity::from(100u64), 67)
+            .expect("closing proposal should work");
+        assert_eq!(p.state, ProposalState::Rejected);
+
+        // Voted stake exceeds total voting stake.
+        let mut p = proposal(vec![(Vote::Yes, 200)]);
+        p.close_proposal(&Quantity::from(100u64), 67)
+            .expect_err("closing proposal should fail");
+
+        // Zero total voting stake.
+        let mut p = proposal(vec![]);
+        p.close_proposal(&Quantity::from(0u64), 67)
+            .expect_err("closing proposal should fail");
+
+        // Results not initialized.
+        let mut p = proposal(vec![]);
+        p.results = None;
+        p.close_proposal(&Quantity::from(100u64), 67)
+            .expect_err("closing proposal should fail");
+
+        // Proposal not active.
+        let mut p = proposal(vec![(Vote::Yes, 100)]);
+        p.state = ProposalState::Passed;
+        p.close_proposal(&Quantity::from(100u64), 67)
+            .expect_err("closing proposal should fail");
+    }
+
+    #[test]
+    fn test_apply_consensus_parameter_changes() {
+        let mut params = ConsensusParameters {
+            voting_period: 10,
+            stake_threshold: 67,
+            ..Default::default()
+        };
+
+        // Empty changes should not modify anything.
+        ConsensusParameterChanges::default().apply(&mut params);
+        assert_eq!(params.voting_period, 10);
+        assert_eq!(params.stake_threshold, 67);
+
+        let changes = ConsensusParameterChanges {
+            voting_period: Some(20),
+            enable_change_parameters_proposal: Some(true),
+            ..Default::default()
+        };
+        changes.apply(&mut params);
+        assert_eq!(params.voting_period, 20);
+        assert_eq!(params.stake_threshold, 67);
+        assert!(params.enable_change_parameters_proposal);
+
+        // Explicitly empty gas costs should clear the existing ones (as a non-nil map in Go).
+        params.gas_costs = [("submit_proposal".to_string(), 1000)].into();
+        let changes = ConsensusParameterChanges {
+            gas_costs: Some(Costs::new()),
+            ..Default::default()
+        };
+        changes.apply(&mut params);
+        assert!(params.gas_costs.is_empty());
+    }
+
     #[test]
     fn test_consistent_proposal_content() {
         // NOTE: These tests MUST be synced with go/governance/api/api_test.go.
@@ -4,8 +4,10 @@ use crate::common::crypto::hash::Hash;
 
 pub mod address;
 pub mod beacon;
+pub mod genesis;
 pub mod governance;
 pub mod keymanager;
+pub mod parameters;
 pub mod registry;
 pub mod roothash;
 pub mod scheduler;
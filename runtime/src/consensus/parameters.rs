@@ -0,0 +1,186 @@
+//! Consensus parameters shared by all backends.
+use std::sync::Mutex;
+
+use tokio::sync::watch;
+
+use crate::{
+    consensus::{
+        beacon::EpochTime,
+        genesis, governance, staking,
+        state::{
+            consensus::ImmutableState as ConsensusImmutableState,
+            governance::ImmutableState as GovernanceImmutableState,
+            staking::ImmutableState as StakingImmutableState, StateError,
+        },
+    },
+    storage::mkvs::ImmutableMKVS,
+};
+
+/// Consensus parameters of all backends that can be changed via governance.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Parameters {
+    /// Consensus backend parameters.
+    pub consensus: genesis::Parameters,
+    /// Staking consensus parameters.
+    pub staking: staking::ConsensusParameters,
+    /// Governance consensus parameters.
+    pub governance: governance::ConsensusParameters,
+}
+
+impl Parameters {
+    /// Loads consensus parameters from the given consensus state.
+    pub fn from_state<T: ImmutableMKVS>(mkvs: &T) -> Result<Self, StateError> {
+        Ok(Self {
+            consensus: ConsensusImmutableState::new(mkvs).consensus_parameters()?,
+            staking: StakingImmutableState::new(mkvs).consensus_parameters()?,
+            governance: GovernanceImmutableState::new(mkvs).consensus_parameters()?,
+        })
+    }
+}
+
+/// Tracks the latest consensus parameters and notifies subscribers when they change.
+///
+/// Parameters are `None` until they have been loaded from verified consensus state.
+pub struct ParametersWatcher {
+    tx: watch::Sender<Option<Parameters>>,
+    last_refresh: Mutex<Option<(u64, EpochTime)>>,
+}
+
+impl ParametersWatcher {
+    /// Creates a new consensus parameters watcher.
+    pub fn new() -> Self {
+        let (tx, _) = watch::channel(None);
+        Self {
+            tx,
+            last_refresh: Mutex::new(None),
+        }
+    }
+
+    /// Returns the latest consensus parameters, if known.
+    pub fn parameters(&self) -> Option<Parameters> {
+        self.tx.borrow().clone()
+    }
+
+    /// Refreshes consensus parameters from the given verified consensus state at the given
+    /// height and epoch.
+    ///
+    /// State is only read when the height or epoch differ from the last successful refresh and
+    /// subscribers are only notified in case the parameters have changed. Returns true if the
+    /// parameters have changed.
+    pub fn update<T: ImmutableMKVS>(
+        &self,
+        mkvs: &T,
+        height: u64,
+        epoch: EpochTime,
+    ) -> Result<bool, StateError> {
+        let mut last_refresh = self.last_refresh.lock().unwrap();
+        if *last_refresh == Some((height, epoch)) {
+            return Ok(false);
+        }
+
+        let params = Parameters::from_state(mkvs)?;
+        *last_refresh = Some((height, epoch));
+
+        Ok(self.tx.send_if_modified(|current| {
+            if current.as_ref() == Some(&params) {
+                return false;
+            }
+            *current = Some(params);
+            true
+        }))
+    }
+
+    /// Subscribes to consensus parameter changes.
+    pub fn watch_parameters(&self) -> watch::Receiver<Option<Parameters>> {
+        self.tx.subscribe()
+    }
+}
+
+impl Default for ParametersWatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        consensus::state::{
+            consensus::MutableState as ConsensusMutableState,
+            governance::MutableState as GovernanceMutableState,
+        },
+        storage::mkvs::{sync::NoopReadSyncer, RootType, Tree},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_watch_parameters() {
+        let mut mkvs = Tree::builder()
+            .with_root_type(RootType::State)
+            .build(Box::new(NoopReadSyncer));
+
+        let watcher = ParametersWatcher::new();
+        let mut rx = watcher.watch_parameters();
+        assert!(
+            watcher.parameters().is_none(),
+            "parameters should be unknown"
+        );
+
+        watcher
+            .update(&mkvs, 1, 1)
+            .expect_err("update should fail without consensus parameters");
+        assert!(
+            !rx.has_changed().unwrap(),
+            "subscribers should not be notified"
+        );
+
+        let consensus_params = genesis::Parameters {
+            max_tx_size: 32 * 1024,
+            ..Default::default()
+        };
+        ConsensusMutableState::set_consensus_parameters(&mut mkvs, consensus_params.clone())
+            .unwrap();
+
+        assert!(
+            watcher.update(&mkvs, 1, 1).unwrap(),
+            "parameters should change"
+        );
+        assert!(rx.has_changed().unwrap(), "subscribers should be notified");
+        assert_eq!(
+            rx.borrow_and_update().as_ref().unwrap().consensus,
+            consensus_params
+        );
+
+        assert!(
+            !watcher.update(&mkvs, 2, 1).unwrap(),
+            "parameters should not change"
+        );
+        assert!(
+            !rx.has_changed().unwrap(),
+            "subscribers should not be notified"
+        );
+
+        let governance_params = governance::ConsensusParameters {
+            voting_period: 10,
+            ..Default::default()
+        };
+        GovernanceMutableState::set_consensus_parameters(&mut mkvs, governance_params.clone())
+            .unwrap();
+
+        assert!(
+            !watcher.update(&mkvs, 2, 1).unwrap(),
+            "state should not be read again at the same height and epoch"
+        );
+        assert!(
+            watcher.update(&mkvs, 2, 2).unwrap(),
+            "parameters should change"
+        );
+        assert!(rx.has_changed().unwrap(), "subscribers should be notified");
+        assert_eq!(
+            rx.borrow_and_update().as_ref().unwrap().governance,
+            governance_params
+        );
+        assert_eq!(watcher.parameters().unwrap().consensus, consensus_params);
+    }
+}
@@ -110,10 +110,69 @@ impl Header {
 
 #[cfg(test)]
 mod tests {
-    use crate::common::{crypto::hash::Hash, namespace::Namespace};
+    extern crate test;
+
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use self::test::{black_box, Bencher};
+
+    use crate::common::{crypto::hash::Hash, namespace::Namespace, testing::test_seed};
 
     use super::*;
 
+    fn random_hash(rng: &mut StdRng) -> Hash {
+        Hash::digest_bytes(&rng.gen::<[u8; 32]>())
+    }
+
+    fn random_header(rng: &mut StdRng) -> Header {
+        Header {
+            version: rng.gen(),
+            namespace: Namespace::from(&rng.gen::<[u8; 32]>()[..]),
+            round: rng.gen(),
+            timestamp: rng.gen(),
+            header_type: match rng.gen_range(0..5) {
+                0 => HeaderType::Invalid,
+                1 => HeaderType::Normal,
+                2 => HeaderType::RoundFailed,
+                3 => HeaderType::EpochTransition,
+                _ => HeaderType::Suspended,
+            },
+            previous_hash: random_hash(rng),
+            io_root: random_hash(rng),
+            state_root: random_hash(rng),
+            messages_hash: random_hash(rng),
+            in_msgs_hash: random_hash(rng),
+        }
+    }
+
+    #[test]
+    fn test_header_roundtrip_random() {
+        let seed = test_seed();
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        for _ in 0..1000 {
+            let header = random_header(&mut rng);
+            let enc = cbor::to_vec(header.clone());
+            let dec: Header = cbor::from_slice(&enc)
+                .unwrap_or_else(|_| panic!("header should deserialize (seed: {})", seed));
+            assert_eq!(dec, header, "header should round-trip (seed: {})", seed);
+            assert_eq!(
+                dec.encoded_hash(),
+                header.encoded_hash(),
+                "header hash should be stable (seed: {})",
+                seed
+            );
+
+            let child = Block::new_empty_block(&Block { header }, 0, HeaderType::Normal);
+            assert_eq!(
+                child.header.previous_hash,
+                dec.encoded_hash(),
+                "child should reference parent (seed: {})",
+                seed
+            );
+        }
+    }
+
     #[test]
     fn test_consistent_hash_header() {
         // NOTE: These hashes MUST be synced with go/roothash/api/block/header_test.go.
@@ -140,4 +199,14 @@ mod tests {
             Hash::from("b17374d9b36796752a787d0726ef44826bfdb3ece52545e126c8e7592663544d")
         );
     }
+
+    #[bench]
+    fn bench_header_encoded_hash(b: &mut Bencher) {
+        let mut rng = StdRng::seed_from_u64(0);
+        let header = random_header(&mut rng);
+
+        b.iter(|| {
+            black_box(header.encoded_hash());
+        });
+    }
 }
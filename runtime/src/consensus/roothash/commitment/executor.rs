@@ -270,7 +270,10 @@ impl OpenCommitment for ExecutorCommitment {
 
 #[cfg(test)]
 mod tests {
-    use crate::common::crypto::hash::Hash;
+    extern crate test;
+
+    use self::test::Bencher;
+    use crate::common::crypto::{hash::Hash, signature::PrivateKey};
 
     use super::*;
 
@@ -409,4 +412,32 @@ mod tests {
             assert_eq!(res.is_err(), should_err, "validate_basic({})", name)
         }
     }
+
+    #[bench]
+    fn bench_executor_commitment_verify(b: &mut Bencher) {
+        let sk = PrivateKey::generate();
+        let runtime_id = Namespace::default();
+        let chain_context = "bench".to_string();
+
+        let mut ec = ExecutorCommitment {
+            node_id: sk.public_key(),
+            header: ExecutorCommitmentHeader {
+                header: ComputeResultsHeader {
+                    round: 42,
+                    io_root: Some(Hash::empty_hash()),
+                    state_root: Some(Hash::empty_hash()),
+                    messages_hash: Some(Hash::empty_hash()),
+                    in_msgs_hash: Some(Hash::empty_hash()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        ec.sign(&sk, &runtime_id, &chain_context).unwrap();
+
+        b.iter(|| {
+            ec.verify(&runtime_id, &chain_context).unwrap();
+        });
+    }
 }
@@ -8,7 +8,7 @@ use std::collections::BTreeMap;
 
 use crate::{
     common::{crypto::hash::Hash, quantity::Quantity},
-    consensus::{address::Address, beacon::EpochTime},
+    consensus::{address::Address, beacon::EpochTime, transaction::Costs},
 };
 
 /// A stake transfer.
@@ -25,6 +25,15 @@ pub struct Withdraw {
     pub amount: Quantity,
 }
 
+/// An allowance change for a beneficiary.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, cbor::Encode, cbor::Decode)]
+pub struct Allow {
+    pub beneficiary: Address,
+    #[cbor(optional)]
+    pub negative: bool,
+    pub amount_change: Quantity,
+}
+
 /// A stake escrow.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, cbor::Encode, cbor::Decode)]
 pub struct Escrow {
@@ -191,6 +200,232 @@ pub struct Slash {
     pub freeze_interval: EpochTime,
 }
 
+/// Reward schedule step.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, cbor::Encode, cbor::Decode)]
+pub struct RewardStep {
+    pub until: EpochTime,
+    pub scale: Quantity,
+}
+
+/// Rules for commission schedule changes.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, cbor::Encode, cbor::Decode)]
+pub struct CommissionScheduleRules {
+    /// Epoch period when commission rates are allowed to be changed.
+    #[cbor(optional)]
+    pub rate_change_interval: EpochTime,
+
+    /// Number of epochs a commission rate bound change must specified in advance.
+    #[cbor(optional)]
+    pub rate_bound_lead: EpochTime,
+
+    /// Maximum number of commission rate steps a commission schedule can specify.
+    #[cbor(optional)]
+    pub max_rate_steps: u16,
+
+    /// Maximum number of commission rate bound steps a commission schedule can specify.
+    #[cbor(optional)]
+    pub max_bound_steps: u16,
+
+    /// Minimum commission rate an account can configure.
+    pub min_commission_rate: Quantity,
+}
+
+/// Staking consensus parameters.
+#[derive(Clone, Debug, Default, PartialEq, Eq, cbor::Encode, cbor::Decode)]
+pub struct ConsensusParameters {
+    #[cbor(optional)]
+    pub thresholds: BTreeMap<ThresholdKind, Quantity>,
+    #[cbor(optional)]
+    pub debonding_interval: EpochTime,
+    #[cbor(optional)]
+    pub reward_schedule: Vec<RewardStep>,
+    #[cbor(optional)]
+    pub signing_reward_threshold_numerator: u64,
+    #[cbor(optional)]
+    pub signing_reward_threshold_denominator: u64,
+    pub commission_schedule_rules: CommissionScheduleRules,
+    #[cbor(optional)]
+    pub slashing: BTreeMap<SlashReason, Slash>,
+    #[cbor(optional)]
+    pub gas_costs: Costs,
+    #[cbor(rename = "min_delegation")]
+    pub min_delegation_amount: Quantity,
+    #[cbor(rename = "min_transfer")]
+    pub min_transfer_amount: Quantity,
+    #[cbor(rename = "min_transact_balance")]
+    pub min_transact_balance: Quantity,
+
+    #[cbor(optional)]
+    pub disable_transfers: bool,
+    #[cbor(optional)]
+    pub disable_delegation: bool,
+    #[cbor(optional)]
+    pub undisable_transfers_from: BTreeMap<Address, bool>,
+
+    /// Whether runtimes are allowed to perform AddEscrow and ReclaimEscrow via runtime messages.
+    #[cbor(optional)]
+    pub allow_escrow_messages: bool,
+
+    /// Maximum number of allowances an account can have. Zero means disabled.
+    #[cbor(optional)]
+    pub max_allowances: u32,
+
+    /// Proportion of block fee portions that go to the proposer.
+    pub fee_split_weight_propose: Quantity,
+    /// Proportion of block fee portions that go to the validator that votes.
+    pub fee_split_weight_vote: Quantity,
+    /// Proportion of block fee portions that go to the next block's proposer.
+    pub fee_split_weight_next_propose: Quantity,
+
+    /// Factor for a reward distributed per epoch to entities that have signed at least a
+    /// threshold fraction of the blocks.
+    pub reward_factor_epoch_signed: Quantity,
+    /// Factor for a reward distributed per block to the entity that proposed the block.
+    pub reward_factor_block_proposed: Quantity,
+}
+
+impl ConsensusParameters {
+    /// Splits the given block fees into the part that is paid to the block proposer and the part
+    /// that is persisted for the voters and the next block's proposer.
+    ///
+    /// Returns `None` in case all fee split weights are zero.
+    pub fn split_block_fees(&self, total_fees: &Quantity) -> Option<(Quantity, Quantity)> {
+        let weight_vq = self.fee_split_weight_vote.clone() + &self.fee_split_weight_next_propose;
+        let weight_pvq = weight_vq.clone() + &self.fee_split_weight_propose;
+
+        let persist = (total_fees.clone() * weight_vq).checked_div(&weight_pvq)?;
+        let propose = total_fees.checked_sub(&persist)?;
+
+        Some((propose, persist))
+    }
+}
+
+/// Allowed staking consensus parameter changes.
+#[derive(Clone, Debug, Default, PartialEq, Eq, cbor::Encode, cbor::Decode)]
+pub struct ConsensusParameterChanges {
+    #[cbor(optional)]
+    pub debonding_interval: Option<EpochTime>,
+    #[cbor(optional)]
+    pub reward_schedule: Option<Vec<RewardStep>>,
+    #[cbor(optional)]
+    pub gas_costs: Option<Costs>,
+    #[cbor(optional, rename = "min_delegation")]
+    pub min_delegation_amount: Option<Quantity>,
+    #[cbor(optional, rename = "min_transfer")]
+    pub min_transfer_amount: Option<Quantity>,
+    #[cbor(optional)]
+    pub min_transact_balance: Option<Quantity>,
+    #[cbor(optional)]
+    pub min_commission_rate: Option<Quantity>,
+    #[cbor(optional)]
+    pub disable_transfers: Option<bool>,
+    #[cbor(optional)]
+    pub disable_delegation: Option<bool>,
+    #[cbor(optional)]
+    pub allow_escrow_messages: Option<bool>,
+    #[cbor(optional)]
+    pub max_allowances: Option<u32>,
+    #[cbor(optional)]
+    pub fee_split_weight_propose: Option<Quantity>,
+    #[cbor(optional)]
+    pub fee_split_weight_vote: Option<Quantity>,
+    #[cbor(optional)]
+    pub fee_split_weight_next_propose: Option<Quantity>,
+    #[cbor(optional)]
+    pub reward_factor_epoch_signed: Option<Quantity>,
+    #[cbor(optional)]
+    pub reward_factor_block_proposed: Option<Quantity>,
+}
+
+impl ConsensusParameterChanges {
+    /// Applies changes to the given consensus parameters.
+    pub fn apply(&self, params: &mut ConsensusParameters) {
+        if let Some(debonding_interval) = self.debonding_interval {
+            params.debonding_interval = debonding_interval;
+        }
+        if let Some(reward_schedule) = &self.reward_schedule {
+            params.reward_schedule = reward_schedule.clone();
+        }
+        if let Some(gas_costs) = &self.gas_costs {
+            params.gas_costs = gas_costs.clone();
+        }
+        if let Some(min_delegation_amount) = &self.min_delegation_amount {
+            params.min_delegation_amount = min_delegation_amount.clone();
+        }
+        if let Some(min_transfer_amount) = &self.min_transfer_amount {
+            params.min_transfer_amount = min_transfer_amount.clone();
+        }
+        if let Some(min_transact_balance) = &self.min_transact_balance {
+            params.min_transact_balance = min_transact_balance.clone();
+        }
+        if let Some(min_commission_rate) = &self.min_commission_rate {
+            params.commission_schedule_rules.min_commission_rate = min_commission_rate.clone();
+        }
+        if let Some(disable_transfers) = self.disable_transfers {
+            params.disable_transfers = disable_transfers;
+        }
+        if let Some(disable_delegation) = self.disable_delegation {
+            params.disable_delegation = disable_delegation;
+        }
+        if let Some(allow_escrow_messages) = self.allow_escrow_messages {
+            params.allow_escrow_messages = allow_escrow_messages;
+        }
+        if let Some(max_allowances) = self.max_allowances {
+            params.max_allowances = max_allowances;
+        }
+        if let Some(fee_split_weight_propose) = &self.fee_split_weight_propose {
+            params.fee_split_weight_propose = fee_split_weight_propose.clone();
+        }
+        if let Some(fee_split_weight_vote) = &self.fee_split_weight_vote {
+            params.fee_split_weight_vote = fee_split_weight_vote.clone();
+        }
+        if let Some(fee_split_weight_next_propose) = &self.fee_split_weight_next_propose {
+            params.fee_split_weight_next_propose = fee_split_weight_next_propose.clone();
+        }
+        if let Some(reward_factor_epoch_signed) = &self.reward_factor_epoch_signed {
+            params.reward_factor_epoch_signed = reward_factor_epoch_signed.clone();
+        }
+        if let Some(reward_factor_block_proposed) = &self.reward_factor_block_proposed {
+            params.reward_factor_block_proposed = reward_factor_block_proposed.clone();
+        }
+    }
+}
+
+/// Staking genesis state.
+#[derive(Clone, Debug, Default, PartialEq, Eq, cbor::Encode, cbor::Decode)]
+pub struct Genesis {
+    /// Staking consensus parameters.
+    #[cbor(rename = "params")]
+    pub parameters: ConsensusParameters,
+
+    /// Token's ticker symbol.
+    pub token_symbol: String,
+    /// Token's value base-10 exponent, i.e. 1 token = 10**token_value_exponent base units.
+    pub token_value_exponent: u8,
+
+    /// Network's total amount of stake in base units.
+    pub total_supply: Quantity,
+    /// Network's common stake pool.
+    pub common_pool: Quantity,
+    /// Collected fees for previous block.
+    pub last_block_fees: Quantity,
+    /// Network's governance deposits.
+    pub governance_deposits: Quantity,
+
+    /// Map of staking accounts.
+    #[cbor(optional)]
+    pub ledger: BTreeMap<Address, Account>,
+
+    /// Nested map of staking delegations of the form: delegatee address -> delegator address ->
+    /// delegation.
+    #[cbor(optional)]
+    pub delegations: BTreeMap<Address, BTreeMap<Address, Delegation>>,
+    /// Nested map of debonding delegations of the form: delegatee address -> delegator address ->
+    /// list of debonding delegations.
+    #[cbor(optional)]
+    pub debonding_delegations: BTreeMap<Address, BTreeMap<Address, Vec<DebondingDelegation>>>,
+}
+
 /// Transfer result.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, cbor::Encode, cbor::Decode)]
 pub struct TransferResult {
@@ -473,6 +708,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_consistent_allow() {
+        let addr2 = Address::from_pk(&PublicKey::from(
+            "bbbfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        ));
+
+        let tcs = vec![
+            ("omtiZW5lZmljaWFyeVUAAAAAAAAAAAAAAAAAAAAAAAAAAABtYW1vdW50X2NoYW5nZUA=", Allow::default()),
+            (
+                "omtiZW5lZmljaWFyeVUAuRI5eJXmRwxR+r7MndyD9wrthqFtYW1vdW50X2NoYW5nZUFk",
+                Allow {
+                    beneficiary: addr2.clone(),
+                    negative: false,
+                    amount_change: Quantity::from(100u32),
+                },
+            ),
+            (
+                "o2huZWdhdGl2ZfVrYmVuZWZpY2lhcnlVALkSOXiV5kcMUfq+zJ3cg/cK7YahbWFtb3VudF9jaGFuZ2VBZA==",
+                Allow {
+                    beneficiary: addr2,
+                    negative: true,
+                    amount_change: Quantity::from(100u32),
+                },
+            ),
+        ];
+        for (encoded_base64, rr) in tcs {
+            let dec: Allow = cbor::from_slice(&base64::decode(encoded_base64).unwrap())
+                .expect("allow should deserialize correctly");
+            assert_eq!(dec, rr, "decoded allow should match the expected value");
+
+            let ser = base64::encode(cbor::to_vec(dec));
+            assert_eq!(ser, encoded_base64, "allow should serialize correctly");
+        }
+    }
+
     #[test]
     fn test_consistent_withdraw_results() {
         let addr1 = Address::from_pk(&PublicKey::from(
@@ -560,6 +830,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_split_block_fees() {
+        let params = ConsensusParameters {
+            fee_split_weight_propose: Quantity::from(2u32),
+            fee_split_weight_vote: Quantity::from(1u32),
+            fee_split_weight_next_propose: Quantity::from(1u32),
+            ..Default::default()
+        };
+        assert_eq!(
+            params.split_block_fees(&Quantity::from(100u32)),
+            Some((Quantity::from(50u32), Quantity::from(50u32)))
+        );
+        assert_eq!(
+            params.split_block_fees(&Quantity::from(7u32)),
+            Some((Quantity::from(4u32), Quantity::from(3u32)))
+        );
+        assert_eq!(
+            params.split_block_fees(&Quantity::from(0u32)),
+            Some((Quantity::from(0u32), Quantity::from(0u32)))
+        );
+
+        let params = ConsensusParameters::default();
+        assert_eq!(params.split_block_fees(&Quantity::from(100u32)), None);
+    }
+
+    #[test]
+    fn test_apply_consensus_parameter_changes() {
+        let mut params = ConsensusParameters {
+            debonding_interval: 10,
+            fee_split_weight_propose: Quantity::from(2u32),
+            ..Default::default()
+        };
+
+        let changes = ConsensusParameterChanges {
+            debonding_interval: Some(20),
+            min_commission_rate: Some(Quantity::from(5u32)),
+            ..Default::default()
+        };
+        changes.apply(&mut params);
+        assert_eq!(params.debonding_interval, 20);
+        assert_eq!(
+            params.commission_schedule_rules.min_commission_rate,
+            Quantity::from(5u32)
+        );
+        assert_eq!(params.fee_split_weight_propose, Quantity::from(2u32));
+
+        // Unset gas costs should not modify anything, explicitly empty ones should clear them.
+        params.gas_costs = [("transfer".to_string(), 1000)].into();
+        ConsensusParameterChanges::default().apply(&mut params);
+        assert_eq!(params.gas_costs.len(), 1);
+
+        let changes = ConsensusParameterChanges {
+            gas_costs: Some(Costs::new()),
+            ..Default::default()
+        };
+        changes.apply(&mut params);
+        assert!(params.gas_costs.is_empty());
+    }
+
     #[test]
     fn test_consistent_events() {
         let addr1 = Address::from_pk(&PublicKey::from(
@@ -0,0 +1,102 @@
+//! Consensus backend state in the consensus layer.
+use anyhow::anyhow;
+
+use crate::{
+    common::key_format::{KeyFormat, KeyFormatAtom},
+    consensus::{genesis::Parameters, state::StateError},
+    key_format,
+    storage::mkvs::{FallibleMKVS, ImmutableMKVS},
+};
+
+/// Consensus backend state wrapper.
+pub struct ImmutableState<'a, T: ImmutableMKVS> {
+    mkvs: &'a T,
+}
+
+impl<'a, T: ImmutableMKVS> ImmutableState<'a, T> {
+    /// Constructs a new ImmutableMKVS.
+    pub fn new(mkvs: &'a T) -> ImmutableState<'a, T> {
+        ImmutableState { mkvs }
+    }
+}
+
+key_format!(ChainContextKeyFmt, 0xf0, ());
+key_format!(ParametersKeyFmt, 0xf1, ());
+
+impl<'a, T: ImmutableMKVS> ImmutableState<'a, T> {
+    /// Returns the stored chain context.
+    pub fn chain_context(&self) -> Result<String, StateError> {
+        match self.mkvs.get(&ChainContextKeyFmt(()).encode()) {
+            Ok(Some(b)) => {
+                String::from_utf8(b).map_err(|err| StateError::Unavailable(anyhow!(err)))
+            }
+            Ok(None) => Ok(String::new()),
+            Err(err) => Err(StateError::Unavailable(anyhow!(err))),
+        }
+    }
+
+    /// Returns the consensus backend parameters.
+    pub fn consensus_parameters(&self) -> Result<Parameters, StateError> {
+        match self.mkvs.get(&ParametersKeyFmt(()).encode()) {
+            Ok(Some(b)) => {
+                cbor::from_slice(&b).map_err(|err| StateError::Unavailable(anyhow!(err)))
+            }
+            Ok(None) => Err(StateError::Unavailable(anyhow!(
+                "expected consensus parameters to be present in app state"
+            ))),
+            Err(err) => Err(StateError::Unavailable(anyhow!(err))),
+        }
+    }
+}
+
+/// Mutable consensus backend state wrapper.
+pub struct MutableState;
+
+impl MutableState {
+    /// Set consensus backend parameters.
+    pub fn set_consensus_parameters<S: FallibleMKVS>(
+        mkvs: &mut S,
+        params: Parameters,
+    ) -> Result<(), StateError> {
+        mkvs.insert(&ParametersKeyFmt(()).encode(), &cbor::to_vec(params))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        consensus::genesis::GAS_OP_TX_BYTE,
+        storage::mkvs::{sync::NoopReadSyncer, RootType, Tree},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_mutable_state() {
+        let mut mkvs = Tree::builder()
+            .with_root_type(RootType::State)
+            .build(Box::new(NoopReadSyncer));
+
+        let consensus_state = ImmutableState::new(&mkvs);
+        consensus_state
+            .consensus_parameters()
+            .expect_err("consensus parameters query should fail without parameters");
+
+        let params = Parameters {
+            timeout_commit: 1_000_000_000,
+            max_tx_size: 32 * 1024,
+            max_block_size: 1024 * 1024,
+            max_block_gas: 10_000,
+            gas_costs: [(GAS_OP_TX_BYTE.to_string(), 1)].iter().cloned().collect(),
+            ..Default::default()
+        };
+        MutableState::set_consensus_parameters(&mut mkvs, params.clone()).unwrap();
+
+        let consensus_state = ImmutableState::new(&mkvs);
+        let stored = consensus_state
+            .consensus_parameters()
+            .expect("consensus parameters query should work");
+        assert_eq!(params, stored, "consensus parameters should match");
+    }
+}
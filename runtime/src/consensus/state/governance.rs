@@ -0,0 +1,294 @@
+//! Governance state in the consensus layer.
+use anyhow::anyhow;
+
+use crate::{
+    common::key_format::{KeyFormat, KeyFormatAtom},
+    consensus::{
+        address::Address,
+        beacon::EpochTime,
+        governance::{ConsensusParameters, Proposal, ProposalState, Vote, VoteEntry},
+        state::StateError,
+    },
+    key_format,
+    storage::mkvs::{FallibleMKVS, ImmutableMKVS},
+};
+
+/// Consensus governance state wrapper.
+pub struct ImmutableState<'a, T: ImmutableMKVS> {
+    mkvs: &'a T,
+}
+
+impl<'a, T: ImmutableMKVS> ImmutableState<'a, T> {
+    /// Constructs a new ImmutableMKVS.
+    pub fn new(mkvs: &'a T) -> ImmutableState<'a, T> {
+        ImmutableState { mkvs }
+    }
+}
+
+key_format!(NextProposalIdentifierKeyFmt, 0x80, ());
+key_format!(ProposalsKeyFmt, 0x81, u64);
+key_format!(ActiveProposalsKeyFmt, 0x82, (EpochTime, u64));
+key_format!(VotesKeyFmt, 0x83, (u64, Address));
+key_format!(PendingUpgradesKeyFmt, 0x84, (EpochTime, u64));
+key_format!(ParametersKeyFmt, 0x85, ());
+
+impl<'a, T: ImmutableMKVS> ImmutableState<'a, T> {
+    /// Returns the next proposal identifier.
+    pub fn next_proposal_identifier(&self) -> Result<u64, StateError> {
+        match self.mkvs.get(&NextProposalIdentifierKeyFmt(()).encode()) {
+            Ok(Some(b)) => {
+                cbor::from_slice(&b).map_err(|err| StateError::Unavailable(anyhow!(err)))
+            }
+            Ok(None) => Ok(0),
+            Err(err) => Err(StateError::Unavailable(anyhow!(err))),
+        }
+    }
+
+    /// Looks up a specific proposal by its identifier.
+    pub fn proposal(&self, id: u64) -> Result<Option<Proposal>, StateError> {
+        match self.mkvs.get(&ProposalsKeyFmt(id).encode()) {
+            Ok(Some(b)) => Ok(Some(
+                cbor::from_slice(&b).map_err(|err| StateError::Unavailable(anyhow!(err)))?,
+            )),
+            Ok(None) => Ok(None),
+            Err(err) => Err(StateError::Unavailable(anyhow!(err))),
+        }
+    }
+
+    /// Returns the list of all proposals.
+    pub fn proposals(&self) -> Result<Vec<Proposal>, StateError> {
+        let mut it = self.mkvs.iter();
+        it.seek(&ProposalsKeyFmt::default().encode_partial(0));
+
+        let mut result: Vec<Proposal> = Vec::new();
+
+        while let Some(value) = it
+            .next()
+            .and_then(|(key, value)| ProposalsKeyFmt::decode(&key).map(|_| value))
+        {
+            result.push(
+                cbor::from_slice(&value).map_err(|err| StateError::Unavailable(anyhow!(err)))?,
+            )
+        }
+
+        Ok(result)
+    }
+
+    /// Returns the list of all active proposals, ordered by their closing epoch.
+    pub fn active_proposals(&self) -> Result<Vec<Proposal>, StateError> {
+        let mut it = self.mkvs.iter();
+        it.seek(&ActiveProposalsKeyFmt::default().encode_partial(0));
+
+        let mut result: Vec<Proposal> = Vec::new();
+
+        while let Some(ActiveProposalsKeyFmt((_, id))) = it
+            .next()
+            .and_then(|(key, _)| ActiveProposalsKeyFmt::decode(&key))
+        {
+            let proposal = self.proposal(id)?.ok_or_else(|| {
+                StateError::Unavailable(anyhow!("active proposal {} not found", id))
+            })?;
+            result.push(proposal);
+        }
+
+        Ok(result)
+    }
+
+    /// Returns the list of votes cast for the given proposal.
+    pub fn votes(&self, proposal_id: u64) -> Result<Vec<VoteEntry>, StateError> {
+        let mut it = self.mkvs.iter();
+        it.seek(&VotesKeyFmt((proposal_id, Default::default())).encode_partial(1));
+
+        let mut result: Vec<VoteEntry> = Vec::new();
+
+        while let Some((VotesKeyFmt((id, voter)), value)) = it
+            .next()
+            .and_then(|(key, value)| VotesKeyFmt::decode(&key).zip(value.into()))
+        {
+            if id != proposal_id {
+                break;
+            }
+
+            let vote: Vote =
+                cbor::from_slice(&value).map_err(|err| StateError::Unavailable(anyhow!(err)))?;
+            result.push(VoteEntry { voter, vote });
+        }
+
+        Ok(result)
+    }
+
+    /// Returns the identifiers of proposals of all pending upgrades, ordered by their epoch.
+    pub fn pending_upgrades(&self) -> Result<Vec<u64>, StateError> {
+        let mut it = self.mkvs.iter();
+        it.seek(&PendingUpgradesKeyFmt::default().encode_partial(0));
+
+        Ok(it
+            .map_while(|(key, _)| PendingUpgradesKeyFmt::decode(&key))
+            .map(|PendingUpgradesKeyFmt((_, id))| id)
+            .collect())
+    }
+
+    /// Returns the governance consensus parameters.
+    pub fn consensus_parameters(&self) -> Result<ConsensusParameters, StateError> {
+        match self.mkvs.get(&ParametersKeyFmt(()).encode()) {
+            Ok(Some(b)) => {
+                cbor::from_slice(&b).map_err(|err| StateError::Unavailable(anyhow!(err)))
+            }
+            Ok(None) => Ok(ConsensusParameters::default()),
+            Err(err) => Err(StateError::Unavailable(anyhow!(err))),
+        }
+    }
+}
+
+/// Mutable consensus governance state wrapper.
+pub struct MutableState;
+
+impl MutableState {
+    /// Set the given proposal, updating the set of active proposals accordingly.
+    pub fn set_proposal<S: FallibleMKVS>(
+        mkvs: &mut S,
+        proposal: Proposal,
+    ) -> Result<(), StateError> {
+        let active_key = ActiveProposalsKeyFmt((proposal.closes_at, proposal.id)).encode();
+        if proposal.state == ProposalState::Active {
+            mkvs.insert(&active_key, &[])?;
+        } else {
+            mkvs.remove(&active_key)?;
+        }
+        mkvs.insert(
+            &ProposalsKeyFmt(proposal.id).encode(),
+            &cbor::to_vec(proposal),
+        )?;
+        Ok(())
+    }
+
+    /// Set a vote for the given proposal.
+    pub fn set_vote<S: FallibleMKVS>(
+        mkvs: &mut S,
+        proposal_id: u64,
+        voter: Address,
+        vote: Vote,
+    ) -> Result<(), StateError> {
+        mkvs.insert(
+            &VotesKeyFmt((proposal_id, voter)).encode(),
+            &cbor::to_vec(vote),
+        )?;
+        Ok(())
+    }
+
+    /// Set governance consensus parameters.
+    pub fn set_consensus_parameters<S: FallibleMKVS>(
+        mkvs: &mut S,
+        params: ConsensusParameters,
+    ) -> Result<(), StateError> {
+        mkvs.insert(&ParametersKeyFmt(()).encode(), &cbor::to_vec(params))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        common::crypto::signature::PublicKey,
+        storage::mkvs::{sync::NoopReadSyncer, RootType, Tree},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_mutable_state() {
+        let mut mkvs = Tree::builder()
+            .with_root_type(RootType::State)
+            .build(Box::new(NoopReadSyncer));
+
+        let voter1 = Address::from_pk(&PublicKey::from(
+            "aaafffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        ));
+        let voter2 = Address::from_pk(&PublicKey::from(
+            "bbbfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        ));
+
+        let active = Proposal {
+            id: 1,
+            state: ProposalState::Active,
+            created_at: 10,
+            closes_at: 20,
+            ..Default::default()
+        };
+        let passed = Proposal {
+            id: 2,
+            state: ProposalState::Passed,
+            created_at: 5,
+            closes_at: 15,
+            ..Default::default()
+        };
+        MutableState::set_proposal(&mut mkvs, active.clone()).unwrap();
+        MutableState::set_proposal(&mut mkvs, passed.clone()).unwrap();
+        MutableState::set_vote(&mut mkvs, 1, voter1.clone(), Vote::Yes).unwrap();
+        MutableState::set_vote(&mut mkvs, 1, voter2.clone(), Vote::No).unwrap();
+        MutableState::set_vote(&mut mkvs, 2, voter1.clone(), Vote::Abstain).unwrap();
+        MutableState::set_consensus_parameters(
+            &mut mkvs,
+            ConsensusParameters {
+                voting_period: 10,
+                stake_threshold: 67,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let governance_state = ImmutableState::new(&mkvs);
+
+        // Test proposals.
+        let proposal = governance_state
+            .proposal(1)
+            .expect("proposal query should work");
+        assert_eq!(Some(active.clone()), proposal, "proposal should match");
+        let proposal = governance_state
+            .proposal(3)
+            .expect("proposal query should work");
+        assert_eq!(None, proposal, "proposal should not exist");
+
+        let proposals = governance_state
+            .proposals()
+            .expect("proposals query should work");
+        assert_eq!(
+            vec![active.clone(), passed],
+            proposals,
+            "proposals should match"
+        );
+
+        let active_proposals = governance_state
+            .active_proposals()
+            .expect("active proposals query should work");
+        assert_eq!(
+            vec![active],
+            active_proposals,
+            "active proposals should match"
+        );
+
+        // Test votes.
+        let votes = governance_state.votes(1).expect("votes query should work");
+        assert_eq!(
+            vec![
+                VoteEntry {
+                    voter: voter1,
+                    vote: Vote::Yes,
+                },
+                VoteEntry {
+                    voter: voter2,
+                    vote: Vote::No,
+                },
+            ],
+            votes,
+            "votes should match"
+        );
+
+        // Test consensus parameters.
+        let params = governance_state
+            .consensus_parameters()
+            .expect("consensus parameters query should work");
+        assert_eq!(10, params.voting_period, "voting period should match");
+        assert_eq!(67, params.stake_threshold, "stake threshold should match");
+    }
+}
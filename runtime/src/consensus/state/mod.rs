@@ -11,6 +11,8 @@ use crate::{
 };
 
 pub mod beacon;
+pub mod consensus;
+pub mod governance;
 pub mod keymanager;
 pub mod registry;
 pub mod roothash;
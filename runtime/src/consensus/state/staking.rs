@@ -11,7 +11,7 @@ use crate::{
     consensus::{
         address::Address,
         beacon::EpochTime,
-        staking::{Account, DebondingDelegation, Delegation},
+        staking::{Account, ConsensusParameters, DebondingDelegation, Delegation},
         state::StateError,
     },
     key_format,
@@ -23,6 +23,19 @@ pub struct ImmutableState<'a, T: ImmutableMKVS> {
     mkvs: &'a T,
 }
 
+/// An entry in the debonding queue.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DebondingQueueEntry {
+    /// Epoch at which the debonding delegation ends.
+    pub epoch: EpochTime,
+    /// Address of the delegator.
+    pub delegator_addr: Address,
+    /// Address of the escrow account.
+    pub escrow_addr: Address,
+    /// The debonding delegation.
+    pub delegation: DebondingDelegation,
+}
+
 impl<'a, T: ImmutableMKVS> ImmutableState<'a, T> {
     /// Constructs a new ImmutableMKVS.
     pub fn new(mkvs: &'a T) -> ImmutableState<'a, T> {
@@ -57,6 +70,32 @@ impl<'a, T: ImmutableMKVS> ImmutableState<'a, T> {
         }
     }
 
+    /// Returns the allowance the given owner account has configured for the beneficiary.
+    pub fn allowance(
+        &self,
+        owner_addr: Address,
+        beneficiary_addr: Address,
+    ) -> Result<Quantity, StateError> {
+        let account = self.account(owner_addr)?;
+        Ok(account
+            .general
+            .allowances
+            .get(&beneficiary_addr)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    /// Returns the staking consensus parameters.
+    pub fn consensus_parameters(&self) -> Result<ConsensusParameters, StateError> {
+        match self.mkvs.get(&ParametersKeyFmt(()).encode()) {
+            Ok(Some(b)) => {
+                cbor::from_slice(&b).map_err(|err| StateError::Unavailable(anyhow!(err)))
+            }
+            Ok(None) => Ok(ConsensusParameters::default()),
+            Err(err) => Err(StateError::Unavailable(anyhow!(err))),
+        }
+    }
+
     fn load_stored_balance<K: KeyFormat>(&self, key_format: K) -> Result<Quantity, StateError> {
         match self.mkvs.get(&key_format.encode()) {
             Ok(Some(b)) => {
@@ -193,6 +232,68 @@ impl<'a, T: ImmutableMKVS> ImmutableState<'a, T> {
 
         Ok(result)
     }
+
+    /// Returns all debonding delegations for the given delegator, keyed by escrow address.
+    pub fn debonding_delegations_for(
+        &self,
+        delegator_addr: Address,
+    ) -> Result<BTreeMap<Address, Vec<DebondingDelegation>>, StateError> {
+        let mut it = self.mkvs.iter();
+        it.seek(
+            &DebondingDelegationKeyFmt((delegator_addr.clone(), Default::default(), 0))
+                .encode_partial(1),
+        );
+
+        let mut result: BTreeMap<Address, Vec<DebondingDelegation>> = BTreeMap::new();
+
+        while let Some((DebondingDelegationKeyFmt((decoded_addr, escrow_addr, _)), value)) = it
+            .next()
+            .and_then(|(key, value)| DebondingDelegationKeyFmt::decode(&key).zip(value.into()))
+        {
+            if decoded_addr != delegator_addr {
+                break;
+            }
+
+            result.entry(escrow_addr).or_default().push(
+                cbor::from_slice(&value).map_err(|err| StateError::Unavailable(anyhow!(err)))?,
+            );
+        }
+
+        Ok(result)
+    }
+
+    /// Returns the debonding queue entries that expire at or before the given epoch.
+    pub fn expired_debonding_queue(
+        &self,
+        epoch: EpochTime,
+    ) -> Result<Vec<DebondingQueueEntry>, StateError> {
+        let mut it = self.mkvs.iter();
+        it.seek(&DebondingQueueKeyFmt::default().encode_partial(0));
+
+        let mut entries = Vec::new();
+        while let Some(DebondingQueueKeyFmt((decoded_epoch, delegator_addr, escrow_addr))) = it
+            .next()
+            .and_then(|(key, _)| DebondingQueueKeyFmt::decode(&key))
+        {
+            if decoded_epoch > epoch {
+                break;
+            }
+
+            let delegation = self.debonding_delegation(
+                delegator_addr.clone(),
+                escrow_addr.clone(),
+                decoded_epoch,
+            )?;
+            entries.push(DebondingQueueEntry {
+                epoch: decoded_epoch,
+                delegator_addr,
+                escrow_addr,
+                delegation,
+            });
+        }
+
+        Ok(entries)
+    }
 }
 
 #[cfg(test)]
@@ -454,6 +555,60 @@ mod test {
             "expected debonding delegations should match"
         );
 
+        // Test debonding delegations for a single delegator.
+        let debonding_for = staking_state
+            .debonding_delegations_for(addrs[1].clone())
+            .expect("debonding delegations for query should work");
+        let expected_debonding_for: BTreeMap<Address, Vec<DebondingDelegation>> = [(
+            addrs[0].clone(),
+            vec![
+                DebondingDelegation {
+                    shares: Quantity::from(1u32),
+                    debond_end_time: 15,
+                },
+                DebondingDelegation {
+                    shares: Quantity::from(1u32),
+                    debond_end_time: 21,
+                },
+            ],
+        )]
+        .iter()
+        .cloned()
+        .collect();
+        assert_eq!(
+            expected_debonding_for, debonding_for,
+            "expected debonding delegations for delegator should match"
+        );
+
+        // Test expired debonding queue.
+        let queue = staking_state
+            .expired_debonding_queue(21)
+            .expect("expired debonding queue query should work");
+        let expected_queue = vec![
+            DebondingQueueEntry {
+                epoch: 15,
+                delegator_addr: addrs[1].clone(),
+                escrow_addr: addrs[0].clone(),
+                delegation: DebondingDelegation {
+                    shares: Quantity::from(1u32),
+                    debond_end_time: 15,
+                },
+            },
+            DebondingQueueEntry {
+                epoch: 21,
+                delegator_addr: addrs[1].clone(),
+                escrow_addr: addrs[0].clone(),
+                delegation: DebondingDelegation {
+                    shares: Quantity::from(1u32),
+                    debond_end_time: 21,
+                },
+            },
+        ];
+        assert_eq!(
+            expected_queue, queue,
+            "expected debonding queue entries should match"
+        );
+
         // Test all stored balances.
         let total_supply = staking_state
             .total_supply()
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use crate::common::{
     crypto::signature::{signature_context_with_chain_separation, Signed},
     quantity::Quantity,
@@ -49,6 +51,12 @@ pub struct Fee {
 /// Consensus gas representation.
 pub type Gas = u64;
 
+/// A unique operation identifier used for gas accounting.
+pub type Op = String;
+
+/// Gas costs for different operations.
+pub type Costs = BTreeMap<Op, Gas>;
+
 /// Method name.
 pub type MethodName = String;
 
@@ -69,3 +77,69 @@ pub struct SignedTransactionWithProof {
     /// Proof of transaction inclusion in a block.
     pub proof: Proof,
 }
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::*;
+    use crate::{
+        common::crypto::hash::Hash,
+        consensus::staking::{Allow, Escrow, ReclaimEscrow, Transfer, Withdraw},
+    };
+
+    fn check_body<T: cbor::Decode + cbor::Encode>(body: &cbor::Value) {
+        let decoded: T = cbor::from_value(body.clone()).expect("body should deserialize");
+        assert_eq!(
+            cbor::to_vec(decoded),
+            cbor::to_vec(body.clone()),
+            "body should round-trip"
+        );
+    }
+
+    /// Location of the staking test vectors generated by go/staking/gen_vectors.
+    static STAKING_TEST_VECTORS: Option<&'static str> = option_env!("OASIS_STAKING_TEST_VECTORS");
+
+    #[test]
+    fn test_staking_vectors() {
+        let path = STAKING_TEST_VECTORS.expect("no staking test vectors configured");
+        let raw = fs::read(path).expect("test vectors should be readable");
+        let vectors: Vec<serde_json::Value> =
+            serde_json::from_slice(&raw).expect("test vectors should be valid JSON");
+
+        let chain_context = format!("{:x}", Hash::digest_bytes(b"staking test vectors"));
+        let decode_field = |v: &serde_json::Value, field: &str| -> Vec<u8> {
+            base64::decode(v[field].as_str().expect("field should be a string"))
+                .expect("field should be valid base64")
+        };
+
+        for v in vectors {
+            if !v["valid"].as_bool().unwrap_or_default() {
+                continue;
+            }
+
+            let encoded_tx = decode_field(&v, "encoded_tx");
+            let tx: Transaction =
+                cbor::from_slice(&encoded_tx).expect("transaction should deserialize");
+            match tx.method.as_str() {
+                "staking.Transfer" => check_body::<Transfer>(&tx.body),
+                "staking.AddEscrow" => check_body::<Escrow>(&tx.body),
+                "staking.ReclaimEscrow" => check_body::<ReclaimEscrow>(&tx.body),
+                "staking.Allow" => check_body::<Allow>(&tx.body),
+                "staking.Withdraw" => check_body::<Withdraw>(&tx.body),
+                _ => {}
+            }
+            assert_eq!(
+                cbor::to_vec(tx),
+                encoded_tx,
+                "transaction should round-trip"
+            );
+
+            let encoded_signed_tx = decode_field(&v, "encoded_signed_tx");
+            let signed_tx: SignedTransaction = cbor::from_slice(&encoded_signed_tx)
+                .expect("signed transaction should deserialize");
+            assert_eq!(signed_tx.blob, encoded_tx, "signed blob should match");
+            assert!(signed_tx.verify(&chain_context), "signature should verify");
+        }
+    }
+}
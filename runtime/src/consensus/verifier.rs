@@ -17,6 +17,10 @@ use crate::{
     types::{self, EventKind},
 };
 
+/// A unique module name for the verifier module.
+pub const MODULE_NAME: &str = "verifier";
+
+/// Errors emitted by the consensus verifier.
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("builder: {0}")]
@@ -44,7 +48,11 @@ pub enum Error {
     Internal,
 }
 
-impl Error {
+impl types::CodedError for Error {
+    fn module_name(&self) -> &str {
+        MODULE_NAME
+    }
+
     fn code(&self) -> u32 {
         match self {
             Error::Builder(_) => 1,
@@ -61,11 +69,7 @@ impl Error {
 
 impl From<Error> for types::Error {
     fn from(e: Error) -> Self {
-        Self {
-            module: "verifier".to_string(),
-            code: e.code(),
-            message: e.to_string(),
-        }
+        types::CodedError::to_error(&e)
     }
 }
 
@@ -13,14 +13,16 @@ use crate::{
     attestation, cache,
     common::{
         crypto::{hash::Hash, signature::Signer},
+        executor::Executor,
         logger::get_logger,
         process,
         sgx::QuotePolicy,
     },
     consensus::{
         beacon::EpochTime,
+        parameters::ParametersWatcher,
         roothash::{self, ComputeResultsHeader, Header, COMPUTE_RESULTS_HEADER_SIGNATURE_CONTEXT},
-        state::keymanager::Status as KeyManagerStatus,
+        state::{beacon::ImmutableState as BeaconState, keymanager::Status as KeyManagerStatus},
         verifier::Verifier,
         LightBlock,
     },
@@ -77,6 +79,8 @@ pub struct PreInitState<'a> {
     pub rpc_dispatcher: &'a mut RpcDispatcher,
     /// Consensus verifier instance.
     pub consensus_verifier: &'a Arc<dyn Verifier>,
+    /// Consensus parameters watcher instance.
+    pub consensus_parameters: &'a Arc<ParametersWatcher>,
 }
 
 /// State returned by the initializer.
@@ -116,6 +120,7 @@ struct TxDispatchState {
     mode: ExecutionMode,
     consensus_block: LightBlock,
     consensus_verifier: Arc<dyn Verifier>,
+    consensus_parameters: Arc<ParametersWatcher>,
     header: Header,
     epoch: EpochTime,
     round_results: roothash::RoundResults,
@@ -134,6 +139,7 @@ struct ProtocolState {
 struct State {
     protocol: Arc<Protocol>,
     consensus_verifier: Arc<dyn Verifier>,
+    consensus_parameters: Arc<ParametersWatcher>,
     dispatcher: Arc<Dispatcher>,
     rpc_demux: Arc<RpcDemux>,
     rpc_dispatcher: Arc<RpcDispatcher>,
@@ -227,12 +233,15 @@ impl Dispatcher {
         info!(self.logger, "Starting the runtime dispatcher");
         let mut rpc_demux = RpcDemux::new(self.identity.clone());
         let mut rpc_dispatcher = RpcDispatcher::default();
+        let consensus_parameters = Arc::new(ParametersWatcher::new());
+        self.init_consensus_parameters(&consensus_verifier, &consensus_parameters);
         let pre_init_state = PreInitState {
             protocol: &protocol,
             identity: &self.identity,
             rpc_demux: &mut rpc_demux,
             rpc_dispatcher: &mut rpc_dispatcher,
             consensus_verifier: &consensus_verifier,
+            consensus_parameters: &consensus_parameters,
         };
         let post_init_state = initializer.init(pre_init_state);
         let txn_dispatcher = post_init_state
@@ -242,6 +251,7 @@ impl Dispatcher {
         let state = State {
             protocol: protocol.clone(),
             consensus_verifier: consensus_verifier.clone(),
+            consensus_parameters,
             dispatcher: self.clone(),
             rpc_demux: Arc::new(rpc_demux),
             rpc_dispatcher: Arc::new(rpc_dispatcher),
@@ -257,6 +267,12 @@ impl Dispatcher {
             cache_set: cache::CacheSet::new(protocol.clone()),
         };
 
+        // Requests are processed by a dedicated executor.
+        let executor = Executor::new(
+            "runtime/dispatcher",
+            protocol.get_config().max_concurrent_requests,
+        );
+
         // Start the async message processing task.
         self.tokio_runtime.block_on(async move {
             while let Some(cmd) = rx.recv().await {
@@ -266,7 +282,7 @@ impl Dispatcher {
                         // Process request in its own task.
                         let state = state.clone();
 
-                        tokio::spawn(async move {
+                        executor.spawn(async move {
                             let protocol = state.protocol.clone();
                             let dispatcher = state.dispatcher.clone();
                             let result = dispatcher.handle_request(state, request).await;
@@ -286,6 +302,29 @@ impl Dispatcher {
         info!(self.logger, "Runtime call dispatcher is terminating");
     }
 
+    /// Loads the initial consensus parameters from the latest verified consensus state.
+    ///
+    /// In case the parameters cannot be loaded, they remain unknown until the first executed
+    /// batch refreshes them.
+    fn init_consensus_parameters(
+        &self,
+        consensus_verifier: &Arc<dyn Verifier>,
+        consensus_parameters: &ParametersWatcher,
+    ) {
+        let result = block_on(consensus_verifier.latest_state())
+            .map_err(Error::from)
+            .and_then(|state| {
+                let epoch = BeaconState::new(&state).epoch()?;
+                consensus_parameters.update(&state, state.height(), epoch)?;
+                Ok(())
+            });
+        if let Err(err) = result {
+            warn!(self.logger, "Failed to load initial consensus parameters";
+                "err" => %err,
+            );
+        }
+    }
+
     async fn handle_request(self: &Arc<Self>, state: State, request: Body) -> Result<Body, Error> {
         match request {
             // Attestation-related requests.
@@ -339,6 +378,7 @@ impl Dispatcher {
                         mode,
                         consensus_block,
                         consensus_verifier: state.consensus_verifier,
+                        consensus_parameters: state.consensus_parameters,
                         header: block.header,
                         epoch,
                         round_results,
@@ -367,6 +407,7 @@ impl Dispatcher {
                         mode: ExecutionMode::Execute,
                         consensus_block,
                         consensus_verifier: state.consensus_verifier,
+                        consensus_parameters: state.consensus_parameters,
                         header: block.header,
                         epoch,
                         round_results: Default::default(),
@@ -395,6 +436,7 @@ impl Dispatcher {
                         mode: ExecutionMode::Execute,
                         consensus_block,
                         consensus_verifier: state.consensus_verifier,
+                        consensus_parameters: state.consensus_parameters,
                         header,
                         epoch,
                         round_results: Default::default(),
@@ -569,6 +611,13 @@ impl Dispatcher {
         // Ensure the runtime is still ready to process requests.
         protocol.ensure_initialized()?;
 
+        // Notify subscribers in case consensus parameters have changed.
+        state.consensus_parameters.update(
+            &consensus_state,
+            consensus_state.height(),
+            state.epoch,
+        )?;
+
         let header = &state.header;
 
         let mut cache = cache_set.execute(Root {
@@ -12,7 +12,10 @@ use super::{
     session::{Builder, Session, SessionInfo},
     types::{Frame, Message, SessionID},
 };
-use crate::{common::time::insecure_posix_system_time, identity::Identity};
+use crate::{common::time::insecure_posix_system_time, identity::Identity, types::CodedError};
+
+/// A unique module name for the demux module.
+pub const MODULE_NAME: &str = "demux";
 
 /// Maximum concurrent EnclaveRPC sessions.
 const MAX_CONCURRENT_SESSIONS: usize = 100;
@@ -36,7 +39,11 @@ pub enum Error {
     Other(#[from] anyhow::Error),
 }
 
-impl Error {
+impl CodedError for Error {
+    fn module_name(&self) -> &str {
+        MODULE_NAME
+    }
+
     fn code(&self) -> u32 {
         match self {
             Error::MalformedPayload(_) => 1,
@@ -49,11 +56,7 @@ impl Error {
 
 impl From<Error> for crate::types::Error {
     fn from(e: Error) -> Self {
-        Self {
-            module: "demux".to_string(),
-            code: e.code(),
-            message: e.to_string(),
-        }
+        e.to_error()
     }
 }
 
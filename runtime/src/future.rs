@@ -1,5 +1,8 @@
 //! Helper functions to use with the asynchronous Tokio runtime.
-use std::future::Future;
+use std::{future::Future, sync::Arc};
+
+use futures::stream::{self, StreamExt};
+use tokio::{sync::Semaphore, task::JoinHandle};
 
 /// Create a new asynchronous Tokio runtime.
 #[cfg(target_env = "sgx")]
@@ -26,3 +29,94 @@ pub fn new_tokio_runtime() -> tokio::runtime::Runtime {
 pub fn block_on<F: Future>(future: F) -> F::Output {
     tokio::runtime::Handle::current().block_on(future)
 }
+
+/// Runs the given futures concurrently, with at most `limit` of them in flight at any time, and
+/// returns their outputs in the original order.
+///
+/// This should be used instead of `join_all` when the number of futures is not bounded.
+pub async fn join_all_bounded<I>(futures: I, limit: usize) -> Vec<<I::Item as Future>::Output>
+where
+    I: IntoIterator,
+    I::Item: Future,
+{
+    stream::iter(futures).buffered(limit.max(1)).collect().await
+}
+
+/// Spawns the given future on the current Tokio runtime once a permit has been acquired from the
+/// given semaphore. The permit is held until the future completes.
+pub fn spawn_limited<F>(semaphore: Arc<Semaphore>, future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    tokio::spawn(async move {
+        let _permit = semaphore
+            .acquire_owned()
+            .await
+            .expect("semaphore must not be closed");
+        future.await
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[test]
+    fn test_join_all_bounded() {
+        let rt = new_tokio_runtime();
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+
+        let futures = (0..100).map(|i| {
+            let in_flight = in_flight.clone();
+            let max_in_flight = max_in_flight.clone();
+            async move {
+                let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(current, Ordering::SeqCst);
+                tokio::task::yield_now().await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                i
+            }
+        });
+
+        let results = rt.block_on(join_all_bounded(futures, 10));
+        assert_eq!(
+            results,
+            (0..100).collect::<Vec<_>>(),
+            "order should be preserved"
+        );
+        assert!(max_in_flight.load(Ordering::SeqCst) <= 10);
+    }
+
+    #[test]
+    fn test_spawn_limited() {
+        let rt = new_tokio_runtime();
+        let semaphore = Arc::new(Semaphore::new(2));
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+
+        rt.block_on(async {
+            let handles: Vec<_> = (0..20)
+                .map(|_| {
+                    let in_flight = in_flight.clone();
+                    let max_in_flight = max_in_flight.clone();
+                    spawn_limited(semaphore.clone(), async move {
+                        let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                        max_in_flight.fetch_max(current, Ordering::SeqCst);
+                        tokio::task::yield_now().await;
+                        in_flight.fetch_sub(1, Ordering::SeqCst);
+                    })
+                })
+                .collect();
+
+            for handle in handles {
+                handle.await.unwrap();
+            }
+        });
+
+        assert!(max_in_flight.load(Ordering::SeqCst) <= 2);
+    }
+}
@@ -0,0 +1,433 @@
+//! Typed storage collections over MKVS.
+//!
+//! Each collection owns all keys starting with its prefix, so collections using distinct
+//! prefixes that are not prefixes of one another can share the same tree. Values are CBOR-encoded.
+//!
+//! Collections backed by a shared reference to an [`ImmutableMKVS`] (e.g., consensus state) are
+//! read-only views, while collections backed by a mutable reference to an [`MKVS`] also support
+//! updates.
+use std::{convert::TryInto, marker::PhantomData};
+
+use thiserror::Error;
+
+use super::mkvs::{self, ImmutableMKVS, Iterator as _, MKVS};
+
+/// Errors emitted by storage collections.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("storage collection: storage error: {0}")]
+    Storage(#[from] anyhow::Error),
+    #[error("storage collection: malformed value: {0}")]
+    MalformedValue(#[from] cbor::DecodeError),
+    #[error("storage collection: malformed queue index")]
+    MalformedQueueIndex,
+}
+
+/// Read access to the store backing a collection.
+///
+/// This is implemented for mutable references to [`MKVS`] stores and for shared references to
+/// [`ImmutableMKVS`] stores.
+pub trait Store {
+    /// Fetch entry with given key.
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, Error>;
+
+    /// Returns an iterator over the store.
+    fn iter(&self) -> Box<dyn mkvs::Iterator + '_>;
+}
+
+impl<M: MKVS + ?Sized> Store for &mut M {
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+        Ok(MKVS::get(&**self, key))
+    }
+
+    fn iter(&self) -> Box<dyn mkvs::Iterator + '_> {
+        MKVS::iter(&**self)
+    }
+}
+
+impl<T: ImmutableMKVS + ?Sized> Store for &T {
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+        Ok(ImmutableMKVS::get(&**self, key)?)
+    }
+
+    fn iter(&self) -> Box<dyn mkvs::Iterator + '_> {
+        ImmutableMKVS::iter(&**self)
+    }
+}
+
+/// Concatenates the given key parts.
+fn make_key(parts: &[&[u8]]) -> Vec<u8> {
+    parts.concat()
+}
+
+/// Iterates over all entries with keys starting with the given prefix, yielding key suffixes.
+fn iter_prefix<'a, S: Store>(
+    store: &'a S,
+    prefix: &'a [u8],
+) -> impl std::iter::Iterator<Item = (Vec<u8>, Vec<u8>)> + 'a {
+    let mut it = store.iter();
+    it.seek(prefix);
+    it.take_while(move |(key, _)| key.starts_with(prefix))
+        .map(move |(key, value)| (key[prefix.len()..].to_vec(), value))
+}
+
+/// A map of keys to values.
+pub struct StorageMap<S, K, V> {
+    mkvs: S,
+    prefix: Vec<u8>,
+    _types: PhantomData<(K, V)>,
+}
+
+impl<S, K, V> StorageMap<S, K, V>
+where
+    S: Store,
+    K: AsRef<[u8]>,
+    V: cbor::Encode + cbor::Decode,
+{
+    /// Create a new map stored under the given prefix.
+    pub fn new(mkvs: S, prefix: &[u8]) -> Self {
+        Self {
+            mkvs,
+            prefix: prefix.to_vec(),
+            _types: PhantomData,
+        }
+    }
+
+    /// Fetch the value stored under the given key.
+    pub fn get(&self, key: &K) -> Result<Option<V>, Error> {
+        match self.mkvs.get(&make_key(&[&self.prefix, key.as_ref()]))? {
+            Some(raw) => Ok(Some(cbor::from_slice(&raw)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Check whether a value is stored under the given key.
+    pub fn contains(&self, key: &K) -> Result<bool, Error> {
+        Ok(self
+            .mkvs
+            .get(&make_key(&[&self.prefix, key.as_ref()]))?
+            .is_some())
+    }
+
+    /// Iterate over all entries of the map in key order.
+    pub fn iter(&self) -> impl std::iter::Iterator<Item = Result<(K, V), Error>> + '_
+    where
+        K: From<Vec<u8>>,
+    {
+        iter_prefix(&self.mkvs, &self.prefix).map(|(key, raw)| {
+            let value = cbor::from_slice(&raw)?;
+            Ok((K::from(key), value))
+        })
+    }
+}
+
+impl<'a, M, K, V> StorageMap<&'a mut M, K, V>
+where
+    M: MKVS + ?Sized,
+    K: AsRef<[u8]>,
+    V: cbor::Encode + cbor::Decode,
+{
+    /// Store the value under the given key.
+    pub fn insert(&mut self, key: &K, value: V) {
+        self.mkvs.insert(
+            &make_key(&[&self.prefix, key.as_ref()]),
+            &cbor::to_vec(value),
+        );
+    }
+
+    /// Remove the value stored under the given key, returning whether it was present.
+    pub fn remove(&mut self, key: &K) -> bool {
+        self.mkvs
+            .remove(&make_key(&[&self.prefix, key.as_ref()]))
+            .is_some()
+    }
+}
+
+/// A set of items.
+pub struct StorageSet<S, T> {
+    mkvs: S,
+    prefix: Vec<u8>,
+    _types: PhantomData<T>,
+}
+
+impl<S, T> StorageSet<S, T>
+where
+    S: Store,
+    T: AsRef<[u8]>,
+{
+    /// Create a new set stored under the given prefix.
+    pub fn new(mkvs: S, prefix: &[u8]) -> Self {
+        Self {
+            mkvs,
+            prefix: prefix.to_vec(),
+            _types: PhantomData,
+        }
+    }
+
+    /// Check whether the item is in the set.
+    pub fn contains(&self, item: &T) -> Result<bool, Error> {
+        Ok(self
+            .mkvs
+            .get(&make_key(&[&self.prefix, item.as_ref()]))?
+            .is_some())
+    }
+
+    /// Iterate over all items of the set in order.
+    pub fn iter(&self) -> impl std::iter::Iterator<Item = T> + '_
+    where
+        T: From<Vec<u8>>,
+    {
+        iter_prefix(&self.mkvs, &self.prefix).map(|(item, _)| T::from(item))
+    }
+}
+
+impl<'a, M, T> StorageSet<&'a mut M, T>
+where
+    M: MKVS + ?Sized,
+    T: AsRef<[u8]>,
+{
+    /// Add the item to the set, returning whether it was newly added.
+    pub fn insert(&mut self, item: &T) -> bool {
+        self.mkvs
+            .insert(&make_key(&[&self.prefix, item.as_ref()]), &[])
+            .is_none()
+    }
+
+    /// Remove the item from the set, returning whether it was present.
+    pub fn remove(&mut self, item: &T) -> bool {
+        self.mkvs
+            .remove(&make_key(&[&self.prefix, item.as_ref()]))
+            .is_some()
+    }
+}
+
+/// Key suffix under which the index of the first queued item is stored.
+const QUEUE_HEAD_KEY: u8 = 0x00;
+/// Key suffix under which the index after the last queued item is stored.
+const QUEUE_TAIL_KEY: u8 = 0x01;
+/// Key prefix under which queued items are stored, followed by the big-endian item index.
+const QUEUE_ITEM_PREFIX: u8 = 0x02;
+
+/// A first-in first-out queue of items.
+pub struct StorageQueue<S, T> {
+    mkvs: S,
+    prefix: Vec<u8>,
+    _types: PhantomData<T>,
+}
+
+impl<S, T> StorageQueue<S, T>
+where
+    S: Store,
+    T: cbor::Encode + cbor::Decode,
+{
+    /// Create a new queue stored under the given prefix.
+    pub fn new(mkvs: S, prefix: &[u8]) -> Self {
+        Self {
+            mkvs,
+            prefix: prefix.to_vec(),
+            _types: PhantomData,
+        }
+    }
+
+    fn get_index(&self, key: u8) -> Result<u64, Error> {
+        match self.mkvs.get(&make_key(&[&self.prefix, &[key]]))? {
+            Some(raw) => Ok(u64::from_be_bytes(
+                raw.try_into().map_err(|_| Error::MalformedQueueIndex)?,
+            )),
+            None => Ok(0),
+        }
+    }
+
+    fn item_key(&self, index: u64) -> Vec<u8> {
+        make_key(&[&self.prefix, &[QUEUE_ITEM_PREFIX], &index.to_be_bytes()])
+    }
+
+    /// Number of items in the queue.
+    pub fn len(&self) -> Result<u64, Error> {
+        self.get_index(QUEUE_TAIL_KEY)?
+            .checked_sub(self.get_index(QUEUE_HEAD_KEY)?)
+            .ok_or(Error::MalformedQueueIndex)
+    }
+
+    /// Whether the queue is empty.
+    pub fn is_empty(&self) -> Result<bool, Error> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Fetch the item at the front of the queue without removing it.
+    pub fn peek(&self) -> Result<Option<T>, Error> {
+        if self.is_empty()? {
+            return Ok(None);
+        }
+
+        let head = self.get_index(QUEUE_HEAD_KEY)?;
+        match self.mkvs.get(&self.item_key(head))? {
+            Some(raw) => Ok(Some(cbor::from_slice(&raw)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<'a, M, T> StorageQueue<&'a mut M, T>
+where
+    M: MKVS + ?Sized,
+    T: cbor::Encode + cbor::Decode,
+{
+    fn set_index(&mut self, key: u8, index: u64) {
+        self.mkvs
+            .insert(&make_key(&[&self.prefix, &[key]]), &index.to_be_bytes());
+    }
+
+    /// Append the item to the back of the queue.
+    pub fn push(&mut self, item: T) -> Result<(), Error> {
+        let tail = self.get_index(QUEUE_TAIL_KEY)?;
+        self.mkvs.insert(&self.item_key(tail), &cbor::to_vec(item));
+        self.set_index(QUEUE_TAIL_KEY, tail + 1);
+        Ok(())
+    }
+
+    /// Remove and return the item at the front of the queue.
+    pub fn pop(&mut self) -> Result<Option<T>, Error> {
+        if self.is_empty()? {
+            return Ok(None);
+        }
+
+        let head = self.get_index(QUEUE_HEAD_KEY)?;
+        let raw = self.mkvs.remove(&self.item_key(head));
+        self.set_index(QUEUE_HEAD_KEY, head + 1);
+
+        match raw {
+            Some(raw) => Ok(Some(cbor::from_slice(&raw)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::storage::mkvs::{sync::NoopReadSyncer, OverlayTree, RootType, Tree};
+
+    fn new_tree() -> OverlayTree<Tree> {
+        OverlayTree::new(
+            Tree::builder()
+                .with_root_type(RootType::State)
+                .build(Box::new(NoopReadSyncer)),
+        )
+    }
+
+    #[test]
+    fn test_storage_map() {
+        let mut tree = new_tree();
+
+        let mut other: StorageMap<_, Vec<u8>, u64> = StorageMap::new(&mut tree, b"b");
+        other.insert(&b"key".to_vec(), 1);
+
+        let mut map: StorageMap<_, Vec<u8>, String> = StorageMap::new(&mut tree, b"a");
+        assert_eq!(map.get(&b"foo".to_vec()).unwrap(), None);
+        assert!(!map.contains(&b"foo".to_vec()).unwrap());
+
+        map.insert(&b"foo".to_vec(), "bar".to_string());
+        map.insert(&b"baz".to_vec(), "qux".to_string());
+        assert_eq!(map.get(&b"foo".to_vec()).unwrap(), Some("bar".to_string()));
+        assert!(map.contains(&b"foo".to_vec()).unwrap());
+
+        let entries: Vec<_> = map.iter().map(Result::unwrap).collect();
+        assert_eq!(
+            entries,
+            vec![
+                (b"baz".to_vec(), "qux".to_string()),
+                (b"foo".to_vec(), "bar".to_string()),
+            ],
+            "iteration should be limited to the map prefix"
+        );
+
+        assert!(map.remove(&b"foo".to_vec()));
+        assert!(!map.remove(&b"foo".to_vec()));
+        assert_eq!(map.get(&b"foo".to_vec()).unwrap(), None);
+    }
+
+    #[test]
+    fn test_storage_map_read_only() {
+        let mut tree = Tree::builder()
+            .with_root_type(RootType::State)
+            .build(Box::new(NoopReadSyncer));
+        {
+            let mut overlay = OverlayTree::new(&mut tree);
+            let mut map: StorageMap<_, Vec<u8>, u64> = StorageMap::new(&mut overlay, b"a");
+            map.insert(&b"foo".to_vec(), 1);
+            overlay.commit().unwrap();
+        }
+
+        // Read-only views should work over immutable stores.
+        let map: StorageMap<_, Vec<u8>, u64> = StorageMap::new(&tree, b"a");
+        assert_eq!(map.get(&b"foo".to_vec()).unwrap(), Some(1));
+        assert!(!map.contains(&b"bar".to_vec()).unwrap());
+        assert_eq!(
+            map.iter().map(Result::unwrap).collect::<Vec<_>>(),
+            vec![(b"foo".to_vec(), 1)]
+        );
+
+        // Malformed values should be reported as errors.
+        {
+            let mut overlay = OverlayTree::new(&mut tree);
+            overlay.insert(b"abar", b"not cbor").unwrap();
+            overlay.commit().unwrap();
+        }
+        let map: StorageMap<_, Vec<u8>, u64> = StorageMap::new(&tree, b"a");
+        assert!(matches!(
+            map.get(&b"bar".to_vec()),
+            Err(Error::MalformedValue(_))
+        ));
+    }
+
+    #[test]
+    fn test_storage_set() {
+        let mut tree = new_tree();
+        let mut set: StorageSet<_, Vec<u8>> = StorageSet::new(&mut tree, b"s");
+
+        assert!(set.insert(&b"foo".to_vec()));
+        assert!(!set.insert(&b"foo".to_vec()));
+        assert!(set.insert(&b"bar".to_vec()));
+        assert!(set.contains(&b"foo".to_vec()).unwrap());
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![b"bar".to_vec(), b"foo".to_vec()]
+        );
+
+        assert!(set.remove(&b"foo".to_vec()));
+        assert!(!set.contains(&b"foo".to_vec()).unwrap());
+    }
+
+    #[test]
+    fn test_storage_queue() {
+        let mut tree = new_tree();
+        let mut queue: StorageQueue<_, u64> = StorageQueue::new(&mut tree, b"q");
+
+        assert!(queue.is_empty().unwrap());
+        assert_eq!(queue.pop().unwrap(), None);
+
+        for i in 0..10 {
+            queue.push(i).unwrap();
+        }
+        assert_eq!(queue.len().unwrap(), 10);
+        assert_eq!(queue.peek().unwrap(), Some(0));
+
+        for i in 0..10 {
+            assert_eq!(
+                queue.pop().unwrap(),
+                Some(i),
+                "items should be popped in order"
+            );
+        }
+        assert!(queue.is_empty().unwrap());
+        assert_eq!(queue.pop().unwrap(), None);
+
+        // Malformed indices should be reported as errors.
+        tree.insert(&make_key(&[b"q", &[QUEUE_TAIL_KEY]]), b"bad")
+            .unwrap();
+        let queue: StorageQueue<_, u64> = StorageQueue::new(&mut tree, b"q");
+        assert!(matches!(queue.len(), Err(Error::MalformedQueueIndex)));
+        assert!(matches!(queue.peek(), Err(Error::MalformedQueueIndex)));
+    }
+}
@@ -3,6 +3,7 @@ use std::sync::Arc;
 
 use crate::types::Error;
 
+pub mod collections;
 pub mod mkvs;
 
 // Re-exports.
@@ -2,6 +2,7 @@
 
 pub mod context;
 pub mod dispatcher;
+pub mod profiler;
 pub mod rwset;
 pub mod tags;
 pub mod tree;
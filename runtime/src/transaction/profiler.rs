@@ -0,0 +1,605 @@
+//! Runtime transaction dispatcher profiler.
+use std::{
+    cell::Cell,
+    collections::{BTreeMap, BTreeSet},
+    sync::{atomic::AtomicBool, Arc, Mutex},
+    time::Instant,
+};
+
+use super::{
+    context::Context,
+    dispatcher::{Dispatcher, ExecuteBatchResult},
+    types::TxnBatch,
+};
+use crate::{
+    common::{crypto::hash::Hash, namespace::Namespace},
+    consensus::roothash,
+    storage::{
+        mkvs::{Iterator, Prefix, WriteLog},
+        MKVS,
+    },
+    types::{CheckTxResult, Error as RuntimeError},
+};
+
+/// Name of the query method that returns the collected profiling statistics.
+pub const METHOD_PROFILER_STATS: &str = "profiler.Stats";
+
+/// Name under which queries for methods not registered with the profiler are recorded.
+pub const UNKNOWN_QUERY_METHOD: &str = "query:<unknown>";
+
+/// Name under which transactions for methods not registered with the profiler handle are
+/// recorded.
+pub const UNKNOWN_TX_METHOD: &str = "tx:<unknown>";
+
+/// Storage operation counters.
+#[derive(Clone, Debug, Default, PartialEq, Eq, cbor::Encode, cbor::Decode)]
+pub struct StorageOpCounts {
+    /// Number of get operations.
+    pub get: u64,
+    /// Number of insert operations.
+    pub insert: u64,
+    /// Number of remove operations.
+    pub remove: u64,
+    /// Number of created iterators.
+    pub iter: u64,
+}
+
+impl StorageOpCounts {
+    fn add(&mut self, other: &StorageOpCounts) {
+        self.get += other.get;
+        self.insert += other.insert;
+        self.remove += other.remove;
+        self.iter += other.iter;
+    }
+}
+
+/// Profiling statistics for a single dispatcher method.
+#[derive(Clone, Debug, Default, PartialEq, Eq, cbor::Encode, cbor::Decode)]
+pub struct MethodStats {
+    /// Number of invocations.
+    pub calls: u64,
+    /// Number of invocations that returned an error.
+    pub failures: u64,
+    /// Total execution time in nanoseconds.
+    pub total_time_ns: u64,
+    /// Maximum execution time of a single invocation in nanoseconds.
+    pub max_time_ns: u64,
+    /// Runtime storage operations performed.
+    pub storage_ops: StorageOpCounts,
+}
+
+/// Handle for recording profiling statistics of transaction handlers.
+///
+/// Transaction dispatchers wrapped by a [`Profiler`] should wrap each transaction handler
+/// invocation in [`ProfilerHandle::profile_tx`] using a handle shared with the profiler.
+#[derive(Clone, Default)]
+pub struct ProfilerHandle {
+    stats: Arc<Mutex<BTreeMap<String, MethodStats>>>,
+    tx_methods: Arc<BTreeSet<String>>,
+}
+
+impl ProfilerHandle {
+    /// Create a new profiler handle.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers transaction methods that should be recorded under their own name.
+    ///
+    /// This must be called before the handle is shared as registrations are not propagated to
+    /// existing clones.
+    pub fn with_tx_methods<'a, I>(mut self, methods: I) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        Arc::make_mut(&mut self.tx_methods).extend(methods.into_iter().map(str::to_string));
+        self
+    }
+
+    /// Returns a snapshot of the collected statistics.
+    pub fn stats(&self) -> BTreeMap<String, MethodStats> {
+        self.stats.lock().unwrap().clone()
+    }
+
+    /// Clears all collected statistics.
+    pub fn reset(&self) {
+        self.stats.lock().unwrap().clear();
+    }
+
+    /// Profiles a single invocation of the given transaction handler.
+    ///
+    /// The invocation is recorded as `tx:<method>` in case the method was registered via
+    /// [`ProfilerHandle::with_tx_methods`] and as [`UNKNOWN_TX_METHOD`] otherwise. Storage
+    /// operations performed by the handler through the passed runtime state are counted.
+    pub fn profile_tx<R, E, F>(&self, method: &str, state: &mut dyn MKVS, f: F) -> Result<R, E>
+    where
+        F: FnOnce(&mut dyn MKVS) -> Result<R, E>,
+    {
+        let name = if self.tx_methods.contains(method) {
+            format!("tx:{method}")
+        } else {
+            UNKNOWN_TX_METHOD.to_string()
+        };
+        self.profile(&name, state, f)
+    }
+
+    fn profile<R, E, F>(&self, name: &str, state: &mut dyn MKVS, f: F) -> Result<R, E>
+    where
+        F: FnOnce(&mut dyn MKVS) -> Result<R, E>,
+    {
+        let mut state = CountingMKVS::new(state);
+
+        let start = Instant::now();
+        let result = f(&mut state);
+        let elapsed = start.elapsed().as_nanos() as u64;
+
+        let mut stats = self.stats.lock().unwrap();
+        let entry = stats.entry(name.to_string()).or_default();
+        entry.calls += 1;
+        if result.is_err() {
+            entry.failures += 1;
+        }
+        entry.total_time_ns += elapsed;
+        entry.max_time_ns = entry.max_time_ns.max(elapsed);
+        entry.storage_ops.add(&state.counts());
+
+        result
+    }
+}
+
+/// Dispatcher wrapper that collects per-method execution timing and storage operation counts.
+///
+/// Transaction handlers are recorded by the wrapped dispatcher through the shared
+/// [`ProfilerHandle`] as `tx:<method>` in case the method was registered via
+/// [`ProfilerHandle::with_tx_methods`] and as [`UNKNOWN_TX_METHOD`] otherwise. Queries are
+/// recorded as `query:<method>` in case the method was registered via
+/// [`Profiler::with_query_methods`] and as [`UNKNOWN_QUERY_METHOD`] otherwise. This way
+/// arbitrary method names cannot grow the statistics without bound.
+///
+/// In non-SGX builds the collected statistics can be retrieved via the [`METHOD_PROFILER_STATS`]
+/// query method. SGX builds do not expose them as timings must not leave the enclave.
+pub struct Profiler<D: Dispatcher> {
+    inner: D,
+    handle: ProfilerHandle,
+    query_methods: BTreeSet<String>,
+}
+
+impl<D: Dispatcher> Profiler<D> {
+    /// Create a new profiler wrapping the given dispatcher which records its transaction
+    /// handlers via the given handle.
+    pub fn new(inner: D, handle: ProfilerHandle) -> Self {
+        Self {
+            inner,
+            handle,
+            query_methods: BTreeSet::new(),
+        }
+    }
+
+    /// Registers query methods that should be recorded under their own name.
+    pub fn with_query_methods<'a, I>(mut self, methods: I) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        self.query_methods
+            .extend(methods.into_iter().map(str::to_string));
+        self
+    }
+
+    /// Returns the handle used for recording statistics.
+    pub fn handle(&self) -> &ProfilerHandle {
+        &self.handle
+    }
+
+    /// Returns a snapshot of the collected statistics.
+    pub fn stats(&self) -> BTreeMap<String, MethodStats> {
+        self.handle.stats()
+    }
+
+    /// Clears all collected statistics.
+    pub fn reset(&self) {
+        self.handle.reset()
+    }
+}
+
+impl<D: Dispatcher> Dispatcher for Profiler<D> {
+    fn execute_batch(
+        &self,
+        ctx: Context,
+        batch: &TxnBatch,
+        in_msgs: &[roothash::IncomingMessage],
+    ) -> Result<ExecuteBatchResult, RuntimeError> {
+        self.inner.execute_batch(ctx, batch, in_msgs)
+    }
+
+    fn schedule_and_execute_batch(
+        &self,
+        ctx: Context,
+        initial_batch: &mut TxnBatch,
+        in_msgs: &[roothash::IncomingMessage],
+    ) -> Result<ExecuteBatchResult, RuntimeError> {
+        self.inner
+            .schedule_and_execute_batch(ctx, initial_batch, in_msgs)
+    }
+
+    fn check_batch(
+        &self,
+        ctx: Context,
+        batch: &TxnBatch,
+    ) -> Result<Vec<CheckTxResult>, RuntimeError> {
+        self.inner.check_batch(ctx, batch)
+    }
+
+    fn finalize(&self, new_storage_root: Hash) {
+        self.inner.finalize(new_storage_root)
+    }
+
+    fn set_abort_batch_flag(&mut self, abort_batch: Arc<AtomicBool>) {
+        self.inner.set_abort_batch_flag(abort_batch)
+    }
+
+    fn query(&self, ctx: Context, method: &str, args: Vec<u8>) -> Result<Vec<u8>, RuntimeError> {
+        #[cfg(not(target_env = "sgx"))]
+        if method == METHOD_PROFILER_STATS {
+            return Ok(cbor::to_vec(self.stats()));
+        }
+
+        let name = if self.query_methods.contains(method) {
+            format!("query:{method}")
+        } else {
+            UNKNOWN_QUERY_METHOD.to_string()
+        };
+
+        let Context {
+            protocol,
+            consensus_block,
+            consensus_state,
+            runtime_state,
+            header,
+            epoch,
+            round_results,
+            max_messages,
+            check_only,
+        } = ctx;
+
+        self.handle.profile(&name, runtime_state, |runtime_state| {
+            let ctx = Context {
+                protocol,
+                consensus_block,
+                consensus_state,
+                runtime_state,
+                header,
+                epoch,
+                round_results,
+                max_messages,
+                check_only,
+            };
+            self.inner.query(ctx, method, args)
+        })
+    }
+}
+
+/// MKVS wrapper that counts storage operations.
+struct CountingMKVS<'a> {
+    inner: &'a mut dyn MKVS,
+    get: Cell<u64>,
+    insert: u64,
+    remove: u64,
+    iter: Cell<u64>,
+}
+
+impl<'a> CountingMKVS<'a> {
+    fn new(inner: &'a mut dyn MKVS) -> Self {
+        Self {
+            inner,
+            get: Cell::new(0),
+            insert: 0,
+            remove: 0,
+            iter: Cell::new(0),
+        }
+    }
+
+    fn counts(&self) -> StorageOpCounts {
+        StorageOpCounts {
+            get: self.get.get(),
+            insert: self.insert,
+            remove: self.remove,
+            iter: self.iter.get(),
+        }
+    }
+}
+
+impl<'a> MKVS for CountingMKVS<'a> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.get.set(self.get.get() + 1);
+        self.inner.get(key)
+    }
+
+    fn cache_contains_key(&self, key: &[u8]) -> bool {
+        self.inner.cache_contains_key(key)
+    }
+
+    fn insert(&mut self, key: &[u8], value: &[u8]) -> Option<Vec<u8>> {
+        self.insert += 1;
+        self.inner.insert(key, value)
+    }
+
+    fn remove(&mut self, key: &[u8]) -> Option<Vec<u8>> {
+        self.remove += 1;
+        self.inner.remove(key)
+    }
+
+    fn prefetch_prefixes(&self, prefixes: &[Prefix], limit: u16) {
+        self.inner.prefetch_prefixes(prefixes, limit)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator + '_> {
+        self.iter.set(self.iter.get() + 1);
+        self.inner.iter()
+    }
+
+    fn commit(&mut self, namespace: Namespace, version: u64) -> anyhow::Result<(WriteLog, Hash)> {
+        self.inner.commit(namespace, version)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        config::Config,
+        consensus::{roothash::Header, state::ConsensusState, LightBlock},
+        dispatcher::{Dispatcher as RuntimeDispatcher, PostInitState, PreInitState},
+        identity::Identity,
+        protocol::{Protocol, Stream},
+        storage::mkvs::{sync::NoopReadSyncer, OverlayTree, RootType, Tree},
+        transaction::dispatcher::ExecuteTxResult,
+    };
+
+    use super::*;
+
+    /// Test dispatcher that inserts each transaction into the runtime state and fails on empty
+    /// transactions. The `bar` transaction is handled by an unregistered method.
+    struct TestDispatcher {
+        profiler: ProfilerHandle,
+    }
+
+    impl TestDispatcher {
+        fn handle_tx(&self, state: &mut dyn MKVS, tx: &[u8]) -> Result<(), RuntimeError> {
+            let method = if tx == b"bar" {
+                "test.Unregistered"
+            } else {
+                "test.Insert"
+            };
+            self.profiler.profile_tx(method, state, |state| {
+                if tx.is_empty() {
+                    return Err(RuntimeError::new("test", 1, "empty transaction"));
+                }
+                state.insert(tx, tx);
+                Ok(())
+            })
+        }
+    }
+
+    impl Dispatcher for TestDispatcher {
+        fn execute_batch(
+            &self,
+            ctx: Context,
+            batch: &TxnBatch,
+            _in_msgs: &[roothash::IncomingMessage],
+        ) -> Result<ExecuteBatchResult, RuntimeError> {
+            let mut results = Vec::new();
+            for tx in batch.iter() {
+                let _ = self.handle_tx(ctx.runtime_state, tx);
+                results.push(ExecuteTxResult {
+                    output: vec![],
+                    tags: vec![],
+                });
+            }
+
+            Ok(ExecuteBatchResult {
+                results,
+                messages: vec![],
+                in_msgs_count: 0,
+                block_tags: vec![],
+                tx_reject_hashes: vec![],
+            })
+        }
+
+        fn check_batch(
+            &self,
+            _ctx: Context,
+            batch: &TxnBatch,
+        ) -> Result<Vec<CheckTxResult>, RuntimeError> {
+            Ok(vec![CheckTxResult::default(); batch.len()])
+        }
+
+        fn query(
+            &self,
+            ctx: Context,
+            _method: &str,
+            args: Vec<u8>,
+        ) -> Result<Vec<u8>, RuntimeError> {
+            Ok(ctx.runtime_state.get(&args).unwrap_or_default())
+        }
+    }
+
+    /// Environment for constructing transaction contexts.
+    struct TestEnv {
+        protocol: Arc<Protocol>,
+        consensus_block: LightBlock,
+        header: Header,
+        round_results: roothash::RoundResults,
+    }
+
+    impl TestEnv {
+        fn new(tokio_rt: &tokio::runtime::Runtime) -> Self {
+            let (stream, _) = Stream::pair().unwrap();
+            let identity = Arc::new(Identity::new());
+            let initializer = |_: PreInitState<'_>| -> PostInitState { Default::default() };
+            let dispatcher = RuntimeDispatcher::new(
+                tokio_rt.handle().clone(),
+                Box::new(initializer),
+                identity.clone(),
+            );
+            let protocol = Arc::new(Protocol::new(
+                tokio_rt.handle().clone(),
+                stream,
+                identity,
+                dispatcher,
+                Config::default(),
+            ));
+
+            Self {
+                protocol,
+                consensus_block: Default::default(),
+                header: Default::default(),
+                round_results: Default::default(),
+            }
+        }
+
+        fn ctx<'a>(&'a self, runtime_state: &'a mut dyn MKVS) -> Context<'a> {
+            let consensus_tree = Tree::builder()
+                .with_root_type(RootType::State)
+                .build(Box::new(NoopReadSyncer));
+
+            Context::new(
+                self.protocol.clone(),
+                &self.consensus_block,
+                ConsensusState::new(0, consensus_tree),
+                runtime_state,
+                &self.header,
+                0,
+                &self.round_results,
+                0,
+                false,
+            )
+        }
+    }
+
+    #[test]
+    fn test_profiler() {
+        let tokio_rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let env = TestEnv::new(&tokio_rt);
+        let tree = Tree::builder()
+            .with_root_type(RootType::State)
+            .build(Box::new(NoopReadSyncer));
+        let mut overlay = OverlayTree::new(tree);
+
+        let handle = ProfilerHandle::new().with_tx_methods(["test.Insert"]);
+        let profiler = Profiler::new(
+            TestDispatcher {
+                profiler: handle.clone(),
+            },
+            handle,
+        )
+        .with_query_methods(["test.Get"]);
+
+        // Registered transaction handlers should be profiled individually, others in a single
+        // bucket.
+        let batch = TxnBatch::new(vec![b"foo".to_vec(), b"bar".to_vec(), vec![]]);
+        let result = profiler
+            .execute_batch(env.ctx(&mut overlay), &batch, &[])
+            .expect("batch execution should succeed");
+        assert_eq!(result.results.len(), 3);
+
+        // Batch checks are not profiled unless the dispatcher records them.
+        profiler
+            .check_batch(env.ctx(&mut overlay), &batch)
+            .expect("batch check should succeed");
+
+        // Registered queries should be recorded under their own name, others in a single bucket.
+        let value = profiler
+            .query(env.ctx(&mut overlay), "test.Get", b"foo".to_vec())
+            .expect("query should succeed");
+        assert_eq!(value, b"foo".to_vec());
+        for method in ["test.Other", "test.Another"] {
+            profiler
+                .query(env.ctx(&mut overlay), method, b"bar".to_vec())
+                .expect("query should succeed");
+        }
+
+        let stats = profiler.stats();
+        assert_eq!(stats.len(), 4, "unexpected entries: {stats:?}");
+
+        let tx = &stats["tx:test.Insert"];
+        assert_eq!(tx.calls, 2);
+        assert_eq!(tx.failures, 1);
+        assert_eq!(tx.storage_ops.insert, 1);
+        assert!(tx.max_time_ns <= tx.total_time_ns);
+
+        let unknown_tx = &stats[UNKNOWN_TX_METHOD];
+        assert_eq!(unknown_tx.calls, 1);
+        assert_eq!(unknown_tx.failures, 0);
+        assert_eq!(unknown_tx.storage_ops.insert, 1);
+
+        let query = &stats["query:test.Get"];
+        assert_eq!(query.calls, 1);
+        assert_eq!(query.storage_ops.get, 1);
+
+        let unknown = &stats[UNKNOWN_QUERY_METHOD];
+        assert_eq!(unknown.calls, 2);
+        assert_eq!(unknown.storage_ops.get, 2);
+
+        // Statistics should be available via the debug query.
+        let raw = profiler
+            .query(env.ctx(&mut overlay), METHOD_PROFILER_STATS, vec![])
+            .expect("stats query should succeed");
+        let dec: BTreeMap<String, MethodStats> =
+            cbor::from_slice(&raw).expect("stats should deserialize");
+        assert_eq!(dec, stats);
+
+        profiler.reset();
+        assert!(profiler.stats().is_empty());
+    }
+
+    #[test]
+    fn test_counting_mkvs() {
+        let tree = Tree::builder()
+            .with_root_type(RootType::State)
+            .build(Box::new(NoopReadSyncer));
+        let mut overlay = OverlayTree::new(tree);
+        let mut mkvs = CountingMKVS::new(&mut overlay);
+
+        mkvs.insert(b"foo", b"bar");
+        mkvs.insert(b"bar", b"foo");
+        assert_eq!(mkvs.get(b"foo"), Some(b"bar".to_vec()));
+        assert_eq!(mkvs.remove(b"bar"), Some(b"foo".to_vec()));
+        assert_eq!(mkvs.iter().count(), 1);
+        assert!(!mkvs.cache_contains_key(b"missing"));
+
+        assert_eq!(
+            mkvs.counts(),
+            StorageOpCounts {
+                get: 1,
+                insert: 2,
+                remove: 1,
+                iter: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_method_stats_cbor() {
+        let stats: BTreeMap<String, MethodStats> = [(
+            "execute_batch".to_string(),
+            MethodStats {
+                calls: 2,
+                failures: 1,
+                total_time_ns: 1000,
+                max_time_ns: 700,
+                storage_ops: StorageOpCounts {
+                    get: 3,
+                    ..Default::default()
+                },
+            },
+        )]
+        .into_iter()
+        .collect();
+
+        let enc = cbor::to_vec(stats.clone());
+        let dec: BTreeMap<String, MethodStats> =
+            cbor::from_slice(&enc).expect("stats should deserialize");
+        assert_eq!(dec, stats, "stats should round-trip");
+    }
+}
@@ -55,10 +55,18 @@ impl From<TxnBatch> for VecDeque<Vec<u8>> {
 
 #[cfg(test)]
 mod tests {
+    extern crate test;
+
+    use self::test::{black_box, Bencher};
     use super::*;
 
     use crate::common::crypto::hash::Hash;
 
+    /// Number of transactions in a benchmark batch.
+    const BENCH_BATCH_SIZE: usize = 1000;
+    /// Size of each transaction in a benchmark batch.
+    const BENCH_TX_SIZE: usize = 1024;
+
     #[test]
     fn test_consistent_hash() {
         let batch = TxnBatch(vec![b"foo".to_vec(), b"bar".to_vec(), b"aaa".to_vec()]);
@@ -68,4 +76,19 @@ mod tests {
             Hash::from("c451dd4fd065b815e784aac6b300e479b2167408f0eebbb95a8bd36b9e71e34d")
         );
     }
+
+    #[bench]
+    fn bench_txn_batch_cbor_roundtrip(b: &mut Bencher) {
+        let batch = TxnBatch(
+            (0..BENCH_BATCH_SIZE)
+                .map(|i| vec![i as u8; BENCH_TX_SIZE])
+                .collect(),
+        );
+
+        b.iter(|| {
+            let enc = cbor::to_vec(batch.clone());
+            let dec: TxnBatch = cbor::from_slice(&enc).unwrap();
+            black_box(dec);
+        });
+    }
 }
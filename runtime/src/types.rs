@@ -309,6 +309,39 @@ impl Error {
             message: msg.to_owned(),
         }
     }
+
+    /// Whether the error has the given module name and code.
+    pub fn is(&self, module: &str, code: u32) -> bool {
+        self.module == module && self.code == code
+    }
+}
+
+/// An error with a stable module name and code, so that clients can branch on it.
+pub trait CodedError: std::error::Error {
+    /// Name of the module the error belongs to.
+    fn module_name(&self) -> &str;
+
+    /// Error code, unique within the module.
+    fn code(&self) -> u32;
+
+    /// Convert the error into a serializable error.
+    fn to_error(&self) -> Error {
+        Error::new(self.module_name(), self.code(), &self.to_string())
+    }
+}
+
+impl CodedError for Error {
+    fn module_name(&self) -> &str {
+        &self.module
+    }
+
+    fn code(&self) -> u32 {
+        self.code
+    }
+
+    fn to_error(&self) -> Error {
+        self.clone()
+    }
 }
 
 impl From<anyhow::Error> for Error {
@@ -421,6 +454,10 @@ pub struct CheckTxMetadata {
     pub sender_seq: u64,
     #[cbor(optional)]
     pub sender_state_seq: u64,
+
+    /// Last round in which the transaction may be scheduled; 0 means no expiry.
+    #[cbor(optional)]
+    pub not_after: u64,
 }
 
 /// Consensus event kind.